@@ -0,0 +1,177 @@
+//! On-disk cache of the raw sportschau pages.
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::Game;
+
+/// How long a cached page is served without asking sportschau again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CacheTtls {
+    /// Scores page while a game is being played.
+    #[serde(with = "secs")]
+    pub live: Duration,
+    /// Scores page before the first kickoff and between games.
+    #[serde(with = "secs")]
+    pub scores: Duration,
+    /// Scores page once every game of the matchday is over, and the pages of a finished match.
+    #[serde(with = "secs")]
+    pub finished: Duration,
+    #[serde(with = "secs")]
+    pub standings: Duration,
+    /// Lineups until the game is over. They are fixed at kickoff, so they are kept longer than
+    /// the scores.
+    #[serde(with = "secs")]
+    pub lineup: Duration,
+}
+
+impl Default for CacheTtls {
+    fn default() -> Self {
+        CacheTtls {
+            live: Duration::from_secs(30),
+            scores: Duration::from_secs(5 * 60),
+            finished: Duration::from_secs(6 * 60 * 60),
+            standings: Duration::from_secs(10 * 60),
+            lineup: Duration::from_secs(60 * 60),
+        }
+    }
+}
+
+impl CacheTtls {
+    /// The TTL of a scores page showing `games`.
+    pub fn for_scores(&self, games: &[Game]) -> Duration {
+        if games.iter().any(|game| game.status.is_live()) {
+            self.live
+        } else if games.iter().all(|game| game.status.is_final()) {
+            self.finished
+        } else {
+            self.scores
+        }
+    }
+}
+
+mod secs {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(d: &Duration, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_u64(d.as_secs())
+    }
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Duration, D::Error> {
+        u64::deserialize(d).map(Duration::from_secs)
+    }
+}
+
+/// A cached response together with what is needed to revalidate it.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Entry {
+    pub url: String,
+    pub fetched_at: DateTime<Utc>,
+    #[serde(with = "secs")]
+    pub ttl: Duration,
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+    pub body: String,
+}
+
+impl Entry {
+    pub fn is_fresh(&self) -> bool {
+        let age = Utc::now().signed_duration_since(self.fetched_at);
+        age.to_std().is_ok_and(|age| age < self.ttl)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct CacheStats {
+    pub entries: usize,
+    pub fresh: usize,
+    pub bytes: u64,
+    pub oldest: Option<DateTime<Utc>>,
+    pub newest: Option<DateTime<Utc>>,
+}
+
+/// A directory with one JSON file per cached URL, laid out like the URL paths.
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Cache { dir: dir.into() }
+    }
+    /// `$XDG_CACHE_HOME/soccer`, or the platform's equivalent.
+    pub fn default_dir() -> Option<PathBuf> {
+        dirs::cache_dir().map(|dir| dir.join("soccer"))
+    }
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn path_for(&self, url: &str) -> PathBuf {
+        let path = url
+            .split_once("://")
+            .map_or(url, |(_, rest)| rest)
+            .trim_matches('/');
+        self.dir.join(format!("{path}.json"))
+    }
+
+    /// The cached response for `url`, fresh or not.
+    pub fn get(&self, url: &str) -> Option<Entry> {
+        let content = fs::read_to_string(self.path_for(url)).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    pub fn put(&self, entry: &Entry) -> io::Result<()> {
+        let path = self.path_for(&entry.url);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_vec(entry)?)
+    }
+
+    pub fn stats(&self) -> io::Result<CacheStats> {
+        let mut stats = CacheStats::default();
+        let mut dirs = vec![self.dir.clone()];
+        while let Some(dir) = dirs.pop() {
+            let entries = match fs::read_dir(&dir) {
+                Ok(entries) => entries,
+                Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(e),
+            };
+            for file in entries {
+                let file = file?;
+                let metadata = file.metadata()?;
+                if metadata.is_dir() {
+                    dirs.push(file.path());
+                    continue;
+                }
+                let Some(entry) = fs::read_to_string(file.path())
+                    .ok()
+                    .and_then(|content| serde_json::from_str::<Entry>(&content).ok())
+                else {
+                    continue;
+                };
+                stats.entries += 1;
+                stats.bytes += metadata.len();
+                if entry.is_fresh() {
+                    stats.fresh += 1;
+                }
+                let fetched_at = entry.fetched_at;
+                stats.oldest = Some(stats.oldest.map_or(fetched_at, |t| t.min(fetched_at)));
+                stats.newest = Some(stats.newest.map_or(fetched_at, |t| t.max(fetched_at)));
+            }
+        }
+        Ok(stats)
+    }
+
+    pub fn clear(&self) -> io::Result<()> {
+        match fs::remove_dir_all(&self.dir) {
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+            result => result,
+        }
+    }
+}
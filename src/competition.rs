@@ -0,0 +1,378 @@
+//! The competitions sportschau.de publishes scores for.
+use chrono::{Datelike, Utc};
+use serde::Serialize;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::SoccerError;
+
+const BASE_URL: &str = "https://www.sportschau.de/live-und-ergebnisse/fussball";
+
+/// What sportschau offers for a competition besides the scores.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Features {
+    /// There is a league table (cups have none).
+    pub standings: bool,
+    /// The match pages have a tactical lineup.
+    pub lineups: bool,
+}
+
+const LEAGUE: Features = Features {
+    standings: true,
+    lineups: true,
+};
+const CUP: Features = Features {
+    standings: false,
+    lineups: true,
+};
+const FOREIGN_LEAGUE: Features = Features {
+    standings: true,
+    lineups: false,
+};
+
+/// A stretch of the table with its own fate at the end of the season.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ZoneKind {
+    ChampionsLeague,
+    EuropaLeague,
+    ConferenceLeague,
+    Promotion,
+    PromotionPlayoff,
+    RelegationPlayoff,
+    Relegation,
+    /// Straight into the round of 16 of a European competition.
+    RoundOf16,
+    /// Into the knockout round play-offs of a European competition.
+    KnockoutPlayoff,
+}
+
+/// How teams level on points are ordered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Tiebreaker {
+    GoalDifference,
+    GoalsScored,
+    /// The games between the teams level on points.
+    HeadToHead,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Zone {
+    pub kind: ZoneKind,
+    /// First and last position of the zone, counted from 1.
+    pub first: usize,
+    pub last: usize,
+}
+
+const fn zone(kind: ZoneKind, first: usize, last: usize) -> Zone {
+    Zone { kind, first, last }
+}
+
+use Tiebreaker::*;
+use ZoneKind::*;
+
+const GOALS: &[Tiebreaker] = &[GoalDifference, GoalsScored];
+const HEAD_TO_HEAD: &[Tiebreaker] = &[HeadToHead, GoalDifference, GoalsScored];
+
+const BUNDESLIGA_ZONES: &[Zone] = &[
+    zone(ChampionsLeague, 1, 4),
+    zone(EuropaLeague, 5, 5),
+    zone(ConferenceLeague, 6, 6),
+    zone(RelegationPlayoff, 16, 16),
+    zone(Relegation, 17, 18),
+];
+const EUROPEAN_ZONES: &[Zone] = &[zone(RoundOf16, 1, 8), zone(KnockoutPlayoff, 9, 24)];
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct Competition {
+    /// Identifier used with `--competition`.
+    pub id: &'static str,
+    pub name: &'static str,
+    /// Path segment of the competition on sportschau.de.
+    pub slug: &'static str,
+    pub features: Features,
+    /// Matchdays of a season: the league phase of European competitions, the rounds of cups.
+    pub matchdays: u32,
+    /// Order of the teams level on points, after the points.
+    pub tiebreakers: &'static [Tiebreaker],
+    /// Coloured sections of the table, as of the 2025/26 season.
+    pub zones: &'static [Zone],
+}
+
+/// Every known competition. The first one is the default.
+pub const COMPETITIONS: &[Competition] = &[
+    Competition {
+        id: "bundesliga",
+        name: "Bundesliga",
+        slug: "deutschland-bundesliga",
+        features: LEAGUE,
+        matchdays: 34,
+        tiebreakers: GOALS,
+        zones: BUNDESLIGA_ZONES,
+    },
+    Competition {
+        id: "2-bundesliga",
+        name: "2. Bundesliga",
+        slug: "deutschland-2-bundesliga",
+        features: LEAGUE,
+        matchdays: 34,
+        tiebreakers: GOALS,
+        zones: &[
+            zone(Promotion, 1, 2),
+            zone(PromotionPlayoff, 3, 3),
+            zone(RelegationPlayoff, 16, 16),
+            zone(Relegation, 17, 18),
+        ],
+    },
+    Competition {
+        id: "3-liga",
+        name: "3. Liga",
+        slug: "deutschland-3-liga",
+        features: LEAGUE,
+        matchdays: 38,
+        tiebreakers: GOALS,
+        zones: &[
+            zone(Promotion, 1, 2),
+            zone(PromotionPlayoff, 3, 3),
+            zone(Relegation, 17, 20),
+        ],
+    },
+    Competition {
+        id: "dfb-pokal",
+        name: "DFB-Pokal",
+        slug: "deutschland-dfb-pokal",
+        features: CUP,
+        matchdays: 6,
+        tiebreakers: &[],
+        zones: &[],
+    },
+    Competition {
+        id: "frauen-bundesliga",
+        name: "Frauen-Bundesliga",
+        slug: "deutschland-frauen-bundesliga",
+        features: LEAGUE,
+        matchdays: 26,
+        tiebreakers: GOALS,
+        zones: &[zone(ChampionsLeague, 1, 3), zone(Relegation, 13, 14)],
+    },
+    Competition {
+        id: "champions-league",
+        name: "UEFA Champions League",
+        slug: "uefa-champions-league",
+        features: LEAGUE,
+        matchdays: 8,
+        tiebreakers: GOALS,
+        zones: EUROPEAN_ZONES,
+    },
+    Competition {
+        id: "europa-league",
+        name: "UEFA Europa League",
+        slug: "uefa-europa-league",
+        features: LEAGUE,
+        matchdays: 8,
+        tiebreakers: GOALS,
+        zones: EUROPEAN_ZONES,
+    },
+    Competition {
+        id: "conference-league",
+        name: "UEFA Conference League",
+        slug: "uefa-conference-league",
+        features: LEAGUE,
+        matchdays: 6,
+        tiebreakers: GOALS,
+        zones: EUROPEAN_ZONES,
+    },
+    Competition {
+        id: "premier-league",
+        name: "Premier League",
+        slug: "england-premier-league",
+        features: FOREIGN_LEAGUE,
+        matchdays: 38,
+        tiebreakers: GOALS,
+        zones: &[
+            zone(ChampionsLeague, 1, 4),
+            zone(EuropaLeague, 5, 5),
+            zone(Relegation, 18, 20),
+        ],
+    },
+    Competition {
+        id: "la-liga",
+        name: "LaLiga",
+        slug: "spanien-primera-division",
+        features: FOREIGN_LEAGUE,
+        matchdays: 38,
+        tiebreakers: HEAD_TO_HEAD,
+        zones: &[
+            zone(ChampionsLeague, 1, 4),
+            zone(EuropaLeague, 5, 6),
+            zone(ConferenceLeague, 7, 7),
+            zone(Relegation, 18, 20),
+        ],
+    },
+    Competition {
+        id: "serie-a",
+        name: "Serie A",
+        slug: "italien-serie-a",
+        features: FOREIGN_LEAGUE,
+        matchdays: 38,
+        tiebreakers: HEAD_TO_HEAD,
+        zones: &[
+            zone(ChampionsLeague, 1, 4),
+            zone(EuropaLeague, 5, 6),
+            zone(ConferenceLeague, 7, 7),
+            zone(Relegation, 18, 20),
+        ],
+    },
+    Competition {
+        id: "ligue-1",
+        name: "Ligue 1",
+        slug: "frankreich-ligue-1",
+        features: FOREIGN_LEAGUE,
+        matchdays: 34,
+        tiebreakers: GOALS,
+        zones: BUNDESLIGA_ZONES,
+    },
+    Competition {
+        id: "eredivisie",
+        name: "Eredivisie",
+        slug: "niederlande-eredivisie",
+        features: FOREIGN_LEAGUE,
+        matchdays: 34,
+        tiebreakers: GOALS,
+        zones: &[
+            zone(ChampionsLeague, 1, 2),
+            zone(EuropaLeague, 3, 3),
+            zone(ConferenceLeague, 4, 4),
+            zone(RelegationPlayoff, 16, 17),
+            zone(Relegation, 18, 18),
+        ],
+    },
+];
+
+impl Competition {
+    /// The page listing the games of the current matchday.
+    pub fn scores_url(&self) -> String {
+        format!("{BASE_URL}/{}/spiele-und-ergebnisse", self.slug)
+    }
+    /// The page listing the games of the latest matchday of a past `season`.
+    pub fn season_scores_url(&self, season: Season) -> String {
+        format!(
+            "{BASE_URL}/{}/saison-{}-{:02}/spiele-und-ergebnisse",
+            self.slug,
+            season.0,
+            (season.0 + 1) % 100
+        )
+    }
+    /// The page listing the games of `matchday`, in the current season if `season` is `None`.
+    pub fn matchday_url(&self, season: Option<Season>, matchday: u32) -> String {
+        let scores_url = match season {
+            Some(season) => self.season_scores_url(season),
+            None => self.scores_url(),
+        };
+        format!("{scores_url}/spieltag-{matchday}")
+    }
+    /// The page with the league table, `None` for cups.
+    pub fn table_url(&self) -> Option<String> {
+        self.features
+            .standings
+            .then(|| format!("{BASE_URL}/{}/tabelle", self.slug))
+    }
+}
+
+/// A season, named by the year it starts in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Season(pub i32);
+
+impl FromStr for Season {
+    type Err = SoccerError;
+
+    /// Parses `2023/24`, `2023-24`, `2023/2024` or just `2023`. Seasons that have not started
+    /// yet are rejected.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid =
+            || SoccerError::InvalidArgument(format!("invalid season '{s}', expected e.g. 2023/24"));
+        let (start, end) = match s.split_once(['/', '-']) {
+            Some((start, end)) => (start, Some(end)),
+            None => (s, None),
+        };
+        let start: i32 = start.trim().parse().map_err(|_| invalid())?;
+        if !(1900..=Utc::now().year()).contains(&start) {
+            return Err(invalid());
+        }
+        if let Some(end) = end {
+            let end: i32 = end.trim().parse().map_err(|_| invalid())?;
+            if end != start + 1 && end != (start + 1) % 100 {
+                return Err(invalid());
+            }
+        }
+        Ok(Season(start))
+    }
+}
+
+impl fmt::Display for Season {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{:02}", self.0, (self.0 + 1) % 100)
+    }
+}
+
+/// Looks up a competition by its id.
+pub fn find(id: &str) -> Result<&'static Competition, SoccerError> {
+    COMPETITIONS
+        .iter()
+        .find(|competition| competition.id.eq_ignore_ascii_case(id))
+        .ok_or_else(|| {
+            let ids: Vec<&str> = COMPETITIONS.iter().map(|c| c.id).collect();
+            SoccerError::InvalidArgument(format!(
+                "unknown competition '{id}', expected one of {}",
+                ids.join(", ")
+            ))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seasons_in_every_spelling() {
+        for s in ["2023/24", "2023-24", "2023/2024", "2023", " 2023 / 24 "] {
+            assert_eq!(s.parse::<Season>().unwrap(), Season(2023), "{s}");
+        }
+        assert_eq!(Season(2023).to_string(), "2023/24");
+    }
+
+    #[test]
+    fn seasons_wrap_around_the_century() {
+        let season: Season = "1999/00".parse().unwrap();
+        assert_eq!(season, Season(1999));
+        assert_eq!(season.to_string(), "1999/00");
+        assert_eq!("1999/2000".parse::<Season>().unwrap(), Season(1999));
+    }
+
+    #[test]
+    fn invalid_seasons_are_rejected() {
+        for s in [
+            "2099/00", "2023/25", "2023/23", "abc", "2023/xy", "", "1899/00",
+        ] {
+            assert!(s.parse::<Season>().is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    fn urls_of_past_seasons_and_matchdays() {
+        let bundesliga = find("bundesliga").unwrap();
+        assert_eq!(
+            bundesliga.season_scores_url(Season(1999)),
+            format!("{BASE_URL}/deutschland-bundesliga/saison-1999-00/spiele-und-ergebnisse")
+        );
+        assert_eq!(
+            bundesliga.matchday_url(Some(Season(2023)), 33),
+            format!("{BASE_URL}/deutschland-bundesliga/saison-2023-24/spiele-und-ergebnisse/spieltag-33")
+        );
+        assert_eq!(
+            bundesliga.matchday_url(None, 7),
+            format!("{BASE_URL}/deutschland-bundesliga/spiele-und-ergebnisse/spieltag-7")
+        );
+    }
+}
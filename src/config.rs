@@ -0,0 +1,206 @@
+//! User settings read from `config.toml`.
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::locale::{parse_tz, Clock, Language};
+use crate::{competition, theme, CacheTtls, Format, SoccerError};
+
+/// The keys understood by [`Config::set`].
+pub const KEYS: &[&str] = &[
+    "competition",
+    "favourites",
+    "tz",
+    "clock",
+    "lang",
+    "theme",
+    "format",
+    "cache.live",
+    "cache.scores",
+    "cache.finished",
+    "cache.standings",
+    "cache.lineup",
+];
+
+/// Defaults for the command line options. Every field may be left out of the file.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Id of the competition shown without `--competition`.
+    pub competition: Option<String>,
+    /// Team names, matched like the team name of `soccer match`.
+    pub favourites: Vec<String>,
+    pub tz: Option<Tz>,
+    pub clock: Option<Clock>,
+    pub lang: Option<Language>,
+    pub theme: Option<String>,
+    pub format: Option<Format>,
+    /// TTLs in seconds.
+    pub cache: CacheTtls,
+}
+
+impl Config {
+    /// `$XDG_CONFIG_HOME/soccer/config.toml`, or the platform's equivalent.
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("soccer").join("config.toml"))
+    }
+
+    /// Reads the config at `path`. A missing file is an empty config.
+    pub fn load(path: &Path) -> Result<Self, SoccerError> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Config::default()),
+            Err(e) => return Err(e.into()),
+        };
+        let invalid = |message: String| SoccerError::Config {
+            path: path.to_path_buf(),
+            message,
+        };
+        let config: Config = toml::from_str(&content).map_err(|e| invalid(e.message().into()))?;
+        if let Some(id) = &config.competition {
+            competition::find(id).map_err(|e| invalid(e.to_string()))?;
+        }
+        if let Some(name) = &config.theme {
+            theme::find(name).map_err(|e| invalid(e.to_string()))?;
+        }
+        Ok(config)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), SoccerError> {
+        let content = self.to_toml()?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, content)?;
+        Ok(())
+    }
+
+    /// Overrides every key for which a `SOCCER_<KEY>` variable is set, e.g. `SOCCER_TZ` or
+    /// `SOCCER_CACHE_LIVE`.
+    pub fn apply_env(&mut self) -> Result<(), SoccerError> {
+        for key in KEYS {
+            let var = format!("SOCCER_{}", key.replace('.', "_").to_uppercase());
+            if let Ok(value) = env::var(&var) {
+                self.set(key, &value)
+                    .map_err(|e| SoccerError::InvalidArgument(format!("{var}: {e}")))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Sets `key` to `value`, checking the value first. An empty value unsets the key.
+    /// `favourites` takes a comma-separated list.
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), SoccerError> {
+        let value = value.trim();
+        let unset = value.is_empty();
+        match key {
+            "competition" => {
+                self.competition = if unset {
+                    None
+                } else {
+                    Some(competition::find(value)?.id.to_string())
+                }
+            }
+            "favourites" => {
+                self.favourites = value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|team| !team.is_empty())
+                    .map(String::from)
+                    .collect()
+            }
+            "tz" => self.tz = (!unset).then(|| parse_tz(value)).transpose()?,
+            "clock" => self.clock = (!unset).then(|| value.parse()).transpose()?,
+            "lang" => self.lang = (!unset).then(|| value.parse()).transpose()?,
+            "theme" => {
+                self.theme = if unset {
+                    None
+                } else {
+                    Some(theme::find(value)?.name.to_string())
+                }
+            }
+            "format" => self.format = (!unset).then(|| value.parse()).transpose()?,
+            _ => {
+                let ttl = match key {
+                    "cache.live" => &mut self.cache.live,
+                    "cache.scores" => &mut self.cache.scores,
+                    "cache.finished" => &mut self.cache.finished,
+                    "cache.standings" => &mut self.cache.standings,
+                    "cache.lineup" => &mut self.cache.lineup,
+                    _ => {
+                        return Err(SoccerError::InvalidArgument(format!(
+                            "unknown key '{key}', expected one of {}",
+                            KEYS.join(", ")
+                        )))
+                    }
+                };
+                let secs = value.parse().map_err(|_| {
+                    SoccerError::InvalidArgument(format!("{key} takes seconds, not '{value}'"))
+                })?;
+                *ttl = Duration::from_secs(secs);
+            }
+        }
+        Ok(())
+    }
+
+    /// The config as it is written to the file.
+    pub fn to_toml(&self) -> Result<String, SoccerError> {
+        toml::to_string_pretty(self).map_err(|e| SoccerError::Io(io::Error::other(e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_checks_keys_and_values() {
+        let mut config = Config::default();
+        config.set("tz", "Europe/London").unwrap();
+        config.set("cache.live", "10").unwrap();
+        config.set("favourites", "bvb, , Bayern").unwrap();
+        assert_eq!(config.tz, Some(chrono_tz::Europe::London));
+        assert_eq!(config.cache.live, Duration::from_secs(10));
+        assert_eq!(config.favourites, ["bvb", "Bayern"]);
+
+        assert!(config.set("colour", "red").is_err());
+        assert!(config.set("tz", "Mars/Olympus").is_err());
+        assert!(config.set("cache.live", "soon").is_err());
+        assert!(config.set("competition", "kreisliga").is_err());
+        // Failed calls leave the config as it was.
+        assert_eq!(config.tz, Some(chrono_tz::Europe::London));
+        assert_eq!(config.cache.live, Duration::from_secs(10));
+
+        config.set("tz", "").unwrap();
+        assert_eq!(config.tz, None);
+    }
+
+    #[test]
+    fn env_overrides_the_file() {
+        let path = env::temp_dir().join(format!("soccer-config-{}.toml", std::process::id()));
+        fs::write(&path, "tz = \"Europe/London\"\nformat = \"json\"\n").unwrap();
+        let mut config = Config::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        env::set_var("SOCCER_TZ", "America/New_York");
+        env::set_var("SOCCER_CACHE_LIVE", "10");
+        let applied = config.apply_env();
+        env::set_var("SOCCER_CACHE_LIVE", "soon");
+        let invalid = Config::default().apply_env();
+        env::remove_var("SOCCER_TZ");
+        env::remove_var("SOCCER_CACHE_LIVE");
+
+        applied.unwrap();
+        assert_eq!(config.tz, Some(chrono_tz::America::New_York));
+        assert_eq!(config.cache.live, Duration::from_secs(10));
+        assert_eq!(config.format, Some(Format::Json));
+        assert!(invalid
+            .unwrap_err()
+            .to_string()
+            .contains("SOCCER_CACHE_LIVE"));
+    }
+}
@@ -0,0 +1,135 @@
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+/// Everything that can go wrong while fetching, parsing or printing.
+#[derive(Debug)]
+pub enum SoccerError {
+    /// The request could not be sent or the response could not be read (DNS, TLS, timeout, ...).
+    Network(reqwest::Error),
+    /// The server answered, but not with a success status.
+    HttpStatus {
+        url: String,
+        status: reqwest::StatusCode,
+    },
+    /// The page does not look the way the scraper expects it to, most likely because sportschau
+    /// changed its markup.
+    Markup(String),
+    /// No game matches the team name given on the command line. `closest` holds the team names
+    /// that come nearest.
+    NoMatch { query: String, closest: Vec<String> },
+    /// Several games match the team name equally well and none could be picked.
+    Ambiguous { query: String, games: Vec<String> },
+    /// The command line could not be understood.
+    InvalidArgument(String),
+    /// Reading a saved page or writing the output failed.
+    Io(io::Error),
+    /// The page is needed in offline mode, but it has never been cached.
+    NotCached(String),
+    /// The config file is no valid TOML or holds a value that is not understood.
+    Config { path: PathBuf, message: String },
+}
+
+impl SoccerError {
+    /// Exit code of the process when it stops because of this error.
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            SoccerError::InvalidArgument(_) => 2,
+            SoccerError::Network(_) => 3,
+            SoccerError::HttpStatus { .. } => 4,
+            SoccerError::Markup(_) => 5,
+            SoccerError::NoMatch { .. } => 6,
+            SoccerError::Io(_) => 7,
+            SoccerError::NotCached(_) => 8,
+            SoccerError::Config { .. } => 9,
+            SoccerError::Ambiguous { .. } => 10,
+        }
+    }
+    /// Whether the page does not exist (yet), as opposed to not being readable right now.
+    pub fn is_not_found(&self) -> bool {
+        match self {
+            SoccerError::HttpStatus { status, .. } => *status == reqwest::StatusCode::NOT_FOUND,
+            SoccerError::Io(e) => e.kind() == io::ErrorKind::NotFound,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for SoccerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SoccerError::Network(e) if e.is_timeout() => {
+                write!(f, "sportschau.de did not answer in time")
+            }
+            SoccerError::Network(e) => write!(f, "could not reach sportschau.de: {e}"),
+            SoccerError::HttpStatus { url, status } => {
+                write!(f, "sportschau.de answered {status} for {url}")
+            }
+            SoccerError::Markup(what) => write!(
+                f,
+                "could not read the sportschau page ({what}); the page layout may have changed"
+            ),
+            SoccerError::NoMatch { query, closest } if closest.is_empty() => {
+                write!(f, "no game matches '{query}'")
+            }
+            SoccerError::NoMatch { query, closest } => write!(
+                f,
+                "no game matches '{query}', closest teams: {}",
+                closest.join(", ")
+            ),
+            SoccerError::Ambiguous { query, games } => write!(
+                f,
+                "'{query}' matches several games ({}), use a more specific name or --first",
+                games.join(", ")
+            ),
+            SoccerError::InvalidArgument(msg) => write!(f, "{msg}"),
+            SoccerError::Io(e) => write!(f, "{e}"),
+            SoccerError::NotCached(url) => {
+                write!(f, "{url} is not cached yet, run once without --offline")
+            }
+            SoccerError::Config { path, message } => {
+                write!(f, "invalid config file {}: {message}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for SoccerError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SoccerError::Network(e) => Some(e),
+            SoccerError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for SoccerError {
+    fn from(e: reqwest::Error) -> Self {
+        match e.status() {
+            Some(status) => SoccerError::HttpStatus {
+                url: e.url().map(|u| u.to_string()).unwrap_or_default(),
+                status,
+            },
+            None => SoccerError::Network(e),
+        }
+    }
+}
+
+impl From<io::Error> for SoccerError {
+    fn from(e: io::Error) -> Self {
+        SoccerError::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for SoccerError {
+    fn from(e: serde_json::Error) -> Self {
+        SoccerError::Io(e.into())
+    }
+}
+
+impl From<csv::Error> for SoccerError {
+    fn from(e: csv::Error) -> Self {
+        SoccerError::Io(e.into())
+    }
+}
@@ -0,0 +1,278 @@
+//! Turns successive snapshots of the scores into goals, kickoffs and final whistles.
+use chrono::{DateTime, Datelike, Utc};
+use chrono_tz::Tz;
+use futures::stream::{self, Stream};
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+
+use crate::watch::next_poll;
+use crate::{DataProvider, Game, Locale, MatchStatus, SoccerError};
+
+/// Something that happened in a game between two snapshots.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Event {
+    pub home: String,
+    pub away: String,
+    /// Score after the event.
+    pub score_home: usize,
+    pub score_away: usize,
+    #[serde(flatten)]
+    pub kind: EventKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum EventKind {
+    KickOff,
+    /// `team` is the name of the team that scored.
+    Goal {
+        team: String,
+    },
+    /// The score went down or was changed in a way no single goal explains, e.g. a goal
+    /// disallowed after a video review.
+    ScoreCorrected,
+    HalfTime,
+    FullTime,
+    /// The game did not start, it was moved to `kickoff`.
+    Postponed {
+        kickoff: DateTime<Tz>,
+    },
+}
+
+impl Event {
+    /// One line describing the event, e.g. `Goal        Eintracht Frankfurt 5 - 2 VfL Bochum
+    /// (VfL Bochum)`. New kickoff times are printed in the zone and language of `locale`.
+    pub fn describe(&self, locale: &Locale) -> String {
+        let game = format!(
+            "{} {} - {} {}",
+            self.home, self.score_home, self.score_away, self.away
+        );
+        match &self.kind {
+            EventKind::KickOff => format!("Kick-off    {game}"),
+            EventKind::Goal { team } => format!("Goal        {game} ({team})"),
+            EventKind::ScoreCorrected => format!("Corrected   {game}"),
+            EventKind::HalfTime => format!("Half-time   {game}"),
+            EventKind::FullTime => format!("Full-time   {game}"),
+            EventKind::Postponed { kickoff } => {
+                let this_year = Utc::now().with_timezone(&locale.tz).year();
+                format!(
+                    "Postponed   {} - {}, now {}, {}",
+                    self.home,
+                    self.away,
+                    locale.date(kickoff, this_year),
+                    locale.time(kickoff)
+                )
+            }
+        }
+    }
+}
+
+/// The events that explain how `previous` turned into `current`. Games are matched by their
+/// teams; games only present in one of the snapshots are ignored.
+pub fn diff(previous: &[Game], current: &[Game]) -> Vec<Event> {
+    let previous: HashMap<(&str, &str), &Game> = previous
+        .iter()
+        .map(|game| ((game.home.as_str(), game.away.as_str()), game))
+        .collect();
+    let mut events = Vec::new();
+    for game in current {
+        let Some(before) = previous.get(&(game.home.as_str(), game.away.as_str())) else {
+            continue;
+        };
+        let event = |kind, score_home, score_away| Event {
+            home: game.home.clone(),
+            away: game.away.clone(),
+            score_home,
+            score_away,
+            kind,
+        };
+
+        let started = |status: MatchStatus| status.is_live() || status == MatchStatus::Over;
+        if !started(before.status) && started(game.status) {
+            events.push(event(EventKind::KickOff, 0, 0));
+        }
+        let called_off =
+            before.status != MatchStatus::Postponed && game.status == MatchStatus::Postponed;
+        // Games without a kickoff time on the page carry the time they were read at instead.
+        let moved =
+            before.kickoff_known && game.kickoff_known && before.timestamp != game.timestamp;
+        if called_off || (!started(game.status) && moved) {
+            let kickoff = game.timestamp;
+            events.push(event(EventKind::Postponed { kickoff }, 0, 0));
+        }
+
+        let (home_before, away_before) = (before.score_home, before.score_away);
+        let (home_now, away_now) = (game.score_home, game.score_away);
+        if home_now < home_before || away_now < away_before {
+            events.push(event(EventKind::ScoreCorrected, home_now, away_now));
+        } else {
+            // Several goals between two polls are reported one by one, the home team's first
+            // since the order is unknown.
+            for home in home_before + 1..=home_now {
+                let team = game.home.clone();
+                events.push(event(EventKind::Goal { team }, home, away_before));
+            }
+            for away in away_before + 1..=away_now {
+                let team = game.away.clone();
+                events.push(event(EventKind::Goal { team }, home_now, away));
+            }
+        }
+
+        if before.status != MatchStatus::HalfTime && game.status == MatchStatus::HalfTime {
+            events.push(event(EventKind::HalfTime, home_now, away_now));
+        }
+        if before.status != MatchStatus::Over && game.status == MatchStatus::Over {
+            events.push(event(EventKind::FullTime, home_now, away_now));
+        }
+    }
+    events
+}
+
+/// Polls the scores at `link` for as long as the stream is consumed and yields every event.
+///
+/// The first poll only sets the baseline. Afterwards the scores are polled at the pace of
+/// [`next_poll`]. A failed poll yields the error and the stream carries on with the next one,
+/// unless the very first poll fails, which ends the stream.
+pub fn follow<'a>(
+    provider: &'a dyn DataProvider,
+    link: &'a str,
+) -> impl Stream<Item = Result<Event, SoccerError>> + 'a {
+    let state = Follow {
+        previous: None,
+        pending: VecDeque::new(),
+        finished: false,
+    };
+    stream::unfold(state, move |mut state| async move {
+        loop {
+            if let Some(event) = state.pending.pop_front() {
+                return Some((Ok(event), state));
+            }
+            if state.finished {
+                return None;
+            }
+            if let Some(games) = &state.previous {
+                tokio::time::sleep(next_poll(games, Utc::now())).await;
+            }
+            match provider.scores(link).await {
+                Ok(games) => {
+                    if let Some(before) = &state.previous {
+                        state.pending.extend(diff(before, &games));
+                    }
+                    state.previous = Some(games);
+                }
+                Err(e) => {
+                    // Without a baseline there is nothing to compare against, so give up.
+                    state.finished = state.previous.is_none();
+                    return Some((Err(e), state));
+                }
+            }
+        }
+    })
+}
+
+struct Follow {
+    previous: Option<Vec<Game>>,
+    pending: VecDeque<Event>,
+    finished: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use chrono_tz::Europe::Berlin;
+
+    fn game(score_home: usize, score_away: usize, status: MatchStatus) -> Game {
+        Game::test(
+            "Eintracht Frankfurt",
+            "VfL Bochum",
+            (score_home, score_away),
+            status,
+        )
+    }
+
+    fn kinds(before: Game, after: Game) -> Vec<EventKind> {
+        diff(&[before], &[after])
+            .into_iter()
+            .map(|event| event.kind)
+            .collect()
+    }
+
+    #[test]
+    fn kickoff() {
+        let kinds = kinds(
+            game(0, 0, MatchStatus::Upcoming),
+            game(0, 0, MatchStatus::Live),
+        );
+        assert_eq!(kinds, [EventKind::KickOff]);
+    }
+
+    #[test]
+    fn goals_are_reported_one_by_one() {
+        let events = diff(
+            &[game(1, 0, MatchStatus::Live)],
+            &[game(2, 1, MatchStatus::Live)],
+        );
+        let goals: Vec<(usize, usize, EventKind)> = events
+            .into_iter()
+            .map(|event| (event.score_home, event.score_away, event.kind))
+            .collect();
+        let goal = |team: &str| EventKind::Goal {
+            team: team.to_string(),
+        };
+        assert_eq!(
+            goals,
+            [
+                (2, 0, goal("Eintracht Frankfurt")),
+                (2, 1, goal("VfL Bochum")),
+            ]
+        );
+    }
+
+    #[test]
+    fn lower_score_is_a_correction() {
+        let kinds = kinds(game(2, 1, MatchStatus::Live), game(1, 1, MatchStatus::Live));
+        assert_eq!(kinds, [EventKind::ScoreCorrected]);
+    }
+
+    #[test]
+    fn half_time_and_full_time() {
+        let kinds_at_break = kinds(
+            game(1, 0, MatchStatus::Live),
+            game(1, 0, MatchStatus::HalfTime),
+        );
+        assert_eq!(kinds_at_break, [EventKind::HalfTime]);
+        let kinds_at_end = kinds(game(1, 0, MatchStatus::Live), game(1, 0, MatchStatus::Over));
+        assert_eq!(kinds_at_end, [EventKind::FullTime]);
+        // Nothing happens between two polls of a finished game.
+        assert!(kinds(game(1, 0, MatchStatus::Over), game(1, 0, MatchStatus::Over)).is_empty());
+    }
+
+    #[test]
+    fn postponement() {
+        let mut moved = game(0, 0, MatchStatus::Upcoming);
+        moved.timestamp = Berlin.with_ymd_and_hms(2024, 10, 20, 17, 30, 0).unwrap();
+        let kickoff = moved.timestamp;
+        assert_eq!(
+            kinds(game(0, 0, MatchStatus::Upcoming), moved),
+            [EventKind::Postponed { kickoff }]
+        );
+
+        let called_off = game(0, 0, MatchStatus::Postponed);
+        let kickoff = called_off.timestamp;
+        assert_eq!(
+            kinds(game(0, 0, MatchStatus::Upcoming), called_off),
+            [EventKind::Postponed { kickoff }]
+        );
+    }
+
+    #[test]
+    fn unknown_kickoff_times_are_no_postponement() {
+        let mut before = game(0, 0, MatchStatus::Upcoming);
+        let mut after = game(0, 0, MatchStatus::Upcoming);
+        before.kickoff_known = false;
+        after.kickoff_known = false;
+        after.timestamp = before.timestamp + chrono::Duration::seconds(30);
+        assert!(kinds(before, after).is_empty());
+    }
+}
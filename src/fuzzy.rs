@@ -1,25 +1,317 @@
-use regex::{Regex};
-use crate::{Game};
+//! Fuzzy team-name matching.
+use crate::{Game, Team};
 
-pub fn fuz(search_query:String, searched_games:Vec<Game>)->Vec<Game>{
-    let mut constucted_pattern = String::with_capacity(search_query.len() * 4 + 2);
-    for ch in search_query.to_lowercase().chars(){
-        constucted_pattern.push(ch);
-        constucted_pattern.push_str(".{0,3}"); 
-    } 
-    let pattern = format!("({})", constucted_pattern); 
-    let re = Regex::new(&pattern).unwrap();
+/// Nicknames and abbreviations, mapped to the name sportschau uses.
+const ALIASES: &[(&str, &str)] = &[
+    ("bvb", "borussia dortmund"),
+    ("fcb", "fc bayern muenchen"),
+    ("s04", "fc schalke 04"),
+    ("hsv", "hamburger sv"),
+    ("gladbach", "borussia moenchengladbach"),
+    ("bmg", "borussia moenchengladbach"),
+    ("leverkusen", "bayer 04 leverkusen"),
+    ("werkself", "bayer 04 leverkusen"),
+    ("sge", "eintracht frankfurt"),
+    ("fck", "1 fc kaiserslautern"),
+];
 
+/// Best possible score, given for an exact match.
+pub const MAX_SCORE: u32 = 100;
+/// Lowest score of a name containing the query as is; below are typos and loose subsequences.
+pub const CONTAINS_SCORE: u32 = 70;
 
-    let mut results: Vec<Game> = Vec::new();
+/// A search query, prepared once and scored against any number of team names.
+pub struct Query {
+    text: String,
+}
+
+impl Query {
+    /// Folds case, umlauts and punctuation of `query` and resolves the aliases.
+    pub fn new(query: &str) -> Self {
+        let text = normalize(query);
+        let text = ALIASES
+            .iter()
+            .find(|(alias, _)| *alias == text)
+            .map_or(text, |(_, name)| name.to_string());
+        Query { text }
+    }
 
-    for game in searched_games {
-        let home = &game.home.to_lowercase();
-        let away = &game.away.to_lowercase();
-        if re.is_match(home) || re.is_match(away){
-            results.push(game);
+    /// How well `name` matches, from [`MAX_SCORE`] for the same name down to 1 for a loose
+    /// subsequence; `None` if it does not match at all.
+    pub fn score(&self, name: &str) -> Option<u32> {
+        let query = self.text.as_str();
+        if query.is_empty() {
+            return None;
+        }
+        let name = normalize(name);
+        if name == query {
+            return Some(MAX_SCORE);
+        }
+        if name.starts_with(query) {
+            return Some(90);
+        }
+        if name.split(' ').any(|word| word.starts_with(query)) {
+            return Some(80);
+        }
+        if name.contains(query) {
+            return Some(CONTAINS_SCORE);
+        }
+        // Typos: compare with each word, and with the whole name for queries of several words.
+        let allowed = (query.chars().count() / 4).min(2) as u32;
+        let distance = name
+            .split(' ')
+            .chain([name.as_str()])
+            .map(|candidate| edit_distance(query, candidate))
+            .min()
+            .unwrap_or(u32::MAX);
+        if distance <= allowed {
+            return Some(60 - 10 * distance);
         }
+        subsequence_score(query, &name)
     }
-    return results;
+
+    pub fn matches(&self, name: &str) -> bool {
+        self.score(name).is_some()
+    }
+}
+
+/// The games whose home or away team matches `query`, best match first, with their scores.
+pub fn rank(query: &str, games: Vec<Game>) -> Vec<(u32, Game)> {
+    let query = Query::new(query);
+    let mut ranked: Vec<(u32, Game)> = games
+        .into_iter()
+        .filter_map(|game| {
+            let score = query.score(&game.home).max(query.score(&game.away))?;
+            Some((score, game))
+        })
+        .collect();
+    // Stable, so games with the same score stay in matchday order.
+    ranked.sort_by(|(a, _), (b, _)| b.cmp(a));
+    ranked
+}
+
+/// Returns the games whose home or away team loosely matches `search_query`, best match first.
+pub fn fuz(search_query: String, searched_games: Vec<Game>) -> Vec<Game> {
+    rank(&search_query, searched_games)
+        .into_iter()
+        .map(|(_, game)| game)
+        .collect()
+}
+
+/// The `count` names closest to `query` by the edit distance to the name or one of its words,
+/// closest first.
+pub fn closest(query: &str, names: &[String], count: usize) -> Vec<String> {
+    let query = Query::new(query);
+    let distance = |name: &str| {
+        let name = normalize(name);
+        name.split(' ')
+            .chain([name.as_str()])
+            .map(|candidate| edit_distance(&query.text, candidate))
+            .min()
+            .unwrap_or(u32::MAX)
+    };
+    let mut names: Vec<(u32, &String)> = names.iter().map(|name| (distance(name), name)).collect();
+    names.sort();
+    names.dedup_by(|(_, a), (_, b)| a == b);
+    names
+        .into_iter()
+        .take(count)
+        .map(|(_, name)| name.clone())
+        .collect()
 }
 
+/// Lower case, umlauts and `ß` spelled out, accents dropped, punctuation turned into single
+/// spaces: `1. FC Köln` becomes `1 fc koeln`.
+pub fn normalize(name: &str) -> String {
+    let mut folded = String::with_capacity(name.len());
+    for ch in name.to_lowercase().chars() {
+        match ch {
+            'ä' => folded.push_str("ae"),
+            'ö' => folded.push_str("oe"),
+            'ü' => folded.push_str("ue"),
+            'ß' => folded.push_str("ss"),
+            'á' | 'à' | 'â' | 'ã' | 'å' => folded.push('a'),
+            'é' | 'è' | 'ê' | 'ë' => folded.push('e'),
+            'í' | 'ì' | 'î' | 'ï' => folded.push('i'),
+            'ó' | 'ò' | 'ô' | 'õ' | 'ø' => folded.push('o'),
+            'ú' | 'ù' | 'û' => folded.push('u'),
+            'ç' | 'ć' | 'č' => folded.push('c'),
+            'ñ' => folded.push('n'),
+            'š' | 'ś' => folded.push('s'),
+            'ž' | 'ź' | 'ż' => folded.push('z'),
+            c if c.is_alphanumeric() => folded.push(c),
+            _ => folded.push(' '),
+        }
+    }
+    folded.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Levenshtein distance between `a` and `b`, counted in characters.
+fn edit_distance(a: &str, b: &str) -> u32 {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<u32> = (0..=b.len() as u32).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i as u32 + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + u32::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// Scores `query` as a subsequence of `name`: every skipped character costs a point, a skip of
+/// more than three characters in a row rules the name out. The match may start anywhere.
+fn subsequence_score(query: &str, name: &str) -> Option<u32> {
+    let query: Vec<char> = query.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (first, rest) = query.split_first()?;
+    (0..name.len())
+        .filter(|&start| name[start] == *first)
+        .filter_map(|start| {
+            let (mut at, mut skipped) = (start, 0);
+            for ch in rest {
+                let gap = name[at + 1..].iter().take(4).position(|c| c == ch)?;
+                skipped += gap;
+                at += gap + 1;
+            }
+            Some(skipped)
+        })
+        .min()
+        .map(|skipped| 50u32.saturating_sub(skipped as u32).max(1))
+}
+
+/// The teams to stand out in the tables. A team is a favourite if its name contains one of the
+/// queries, typos are not forgiven so that e.g. `Bayern` does not pick up `Bayer 04`.
+#[derive(Default)]
+pub struct Favourites {
+    queries: Vec<Query>,
+}
+
+impl Favourites {
+    pub fn new(names: &[String]) -> Self {
+        Favourites {
+            queries: names.iter().map(|name| Query::new(name)).collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queries.is_empty()
+    }
+
+    /// Whether the team called `name` is a favourite.
+    pub fn contains(&self, name: &str) -> bool {
+        self.queries
+            .iter()
+            .any(|query| query.score(name) >= Some(CONTAINS_SCORE))
+    }
+
+    /// Whether a favourite plays in `game`.
+    pub fn plays_in(&self, game: &Game) -> bool {
+        self.contains(&game.home) || self.contains(&game.away)
+    }
+
+    /// Whether `team` is a favourite, by its full, short or abbreviated name.
+    pub fn includes(&self, team: &Team) -> bool {
+        [&team.name, &team.short, &team.abbrev]
+            .iter()
+            .any(|name| self.contains(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MatchStatus;
+
+    fn score(query: &str, name: &str) -> Option<u32> {
+        Query::new(query).score(name)
+    }
+
+    fn game(home: &str, away: &str) -> Game {
+        Game::test(home, away, (0, 0), MatchStatus::Upcoming)
+    }
+
+    #[test]
+    fn normalize_folds_case_umlauts_and_punctuation() {
+        assert_eq!(normalize("1. FC Köln"), "1 fc koeln");
+        assert_eq!(normalize("  Atlético  Madrid "), "atletico madrid");
+    }
+
+    #[test]
+    fn exact_over_prefix_over_word_over_contains() {
+        let name = "Borussia Dortmund";
+        assert_eq!(score("borussia dortmund", name), Some(MAX_SCORE));
+        assert_eq!(score("boruss", name), Some(90));
+        assert_eq!(score("dort", name), Some(80));
+        assert_eq!(score("tmund", name), Some(CONTAINS_SCORE));
+    }
+
+    #[test]
+    fn prefix_ranks_above_subsequence() {
+        let prefix = score("dort", "Borussia Dortmund").unwrap();
+        let subsequence = score("dtmd", "Borussia Dortmund").unwrap();
+        assert!(subsequence < CONTAINS_SCORE);
+        assert!(prefix > subsequence);
+        // Gaps of more than three characters rule a name out.
+        assert_eq!(score("bd", "Borussia Dortmund"), None);
+    }
+
+    #[test]
+    fn typos_are_tolerated_by_query_length() {
+        assert_eq!(score("dortmnd", "Borussia Dortmund"), Some(50));
+        assert_eq!(score("leipzg", "RB Leipzig"), Some(50));
+        assert_eq!(score("xyzmund", "Borussia Dortmund"), None);
+    }
+
+    #[test]
+    fn aliases_resolve_to_the_sportschau_name() {
+        assert_eq!(score("bvb", "Borussia Dortmund"), Some(MAX_SCORE));
+        assert_eq!(score("BVB", "Borussia Dortmund"), Some(MAX_SCORE));
+        assert_eq!(
+            score("gladbach", "Borussia Mönchengladbach"),
+            Some(MAX_SCORE)
+        );
+        assert_eq!(score("gladbach", "Borussia Dortmund"), None);
+    }
+
+    #[test]
+    fn ties_keep_the_matchday_order() {
+        let games = vec![
+            game("Borussia Dortmund", "FC St. Pauli"),
+            game("FC Bayern München", "VfB Stuttgart"),
+            game("1. FC Union Berlin", "Borussia Mönchengladbach"),
+        ];
+        let ranked: Vec<(u32, String)> = rank("borussia", games)
+            .into_iter()
+            .map(|(score, game)| (score, game.home))
+            .collect();
+        assert_eq!(
+            ranked,
+            [
+                (90, "Borussia Dortmund".to_string()),
+                (90, "1. FC Union Berlin".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn closest_names_by_edit_distance() {
+        let names: Vec<String> = ["Borussia Dortmund", "FC St. Pauli", "Borussia Dortmund"]
+            .iter()
+            .map(|name| name.to_string())
+            .collect();
+        assert_eq!(
+            closest("dortmnudd", &names, 3),
+            ["Borussia Dortmund", "FC St. Pauli"]
+        );
+    }
+
+    #[test]
+    fn favourites_do_not_forgive_typos() {
+        let favourites = Favourites::new(&["Bayern".to_string()]);
+        assert!(favourites.contains("FC Bayern München"));
+        assert!(!favourites.contains("Bayer 04 Leverkusen"));
+    }
+}
@@ -0,0 +1,74 @@
+//! Scores, standings and lineups of the Bundesliga and the other competitions listed in
+//! [`competition::COMPETITIONS`], scraped from sportschau.de.
+//!
+//! The data is fetched through a [`DataProvider`]: [`Sportschau`] reads the live pages,
+//! [`Fixtures`] reads saved copies of them from disk. The renderers print the results the same
+//! way the `soccer` binary does.
+//!
+//! ```no_run
+//! # async fn run() -> Result<(), soccer::SoccerError> {
+//! let bundesliga = soccer::competition::find("bundesliga")?;
+//! let games = soccer::gather_scores(&bundesliga.scores_url()).await?;
+//! for game in &games {
+//!     println!("{} {}:{} {}", game.home, game.score_home, game.score_away, game.away);
+//! }
+//! soccer::print_scores(games, &soccer::Settings::default());
+//! # Ok(())
+//! # }
+//! ```
+pub mod cache;
+pub mod competition;
+pub mod config;
+pub mod error;
+pub mod events;
+pub mod fuzzy;
+pub mod locale;
+pub mod models;
+pub mod output;
+pub mod provider;
+pub mod render;
+pub mod schedule;
+pub mod standings;
+pub mod theme;
+pub mod watch;
+
+pub use cache::{Cache, CacheTtls};
+pub use competition::Competition;
+pub use config::Config;
+pub use error::SoccerError;
+pub use locale::Locale;
+pub use models::{Game, LineUp, MatchEvent, MatchEventKind, MatchStatus, Player, ShortNames, Team};
+pub use output::Format;
+pub use provider::{get_lineup_link, DataProvider, Fixtures, Sportschau};
+pub use render::{construct_stadium, populate_stadium, print_scores, print_standings, Settings};
+
+/// Fetches the games of the current matchday from the sportschau page at `link`.
+pub async fn gather_scores(link: &str) -> Result<Vec<Game>, SoccerError> {
+    Sportschau::new().scores(link).await
+}
+
+/// Fetches the games of `matchday` of `competition`, in the current season if `season` is `None`.
+pub async fn gather_matchday(
+    competition: &Competition,
+    season: Option<competition::Season>,
+    matchday: u32,
+) -> Result<Vec<Game>, SoccerError> {
+    Sportschau::new()
+        .scores(&competition.matchday_url(season, matchday))
+        .await
+}
+
+/// Fetches the league table from the sportschau page at `link` (see [`Competition::table_url`]).
+pub async fn gather_standings(link: &str) -> Result<Vec<Team>, SoccerError> {
+    Sportschau::new().standings(link).await
+}
+
+/// Fetches both lineups of the match at `link` (see [`Game::link`]).
+pub async fn get_lineup(link: &str) -> Result<Vec<LineUp>, SoccerError> {
+    Sportschau::new().lineup(link).await
+}
+
+/// Fetches the goals, cards and substitutions of the match at `link` (see [`Game::link`]).
+pub async fn get_events(link: &str) -> Result<Vec<MatchEvent>, SoccerError> {
+    Sportschau::new().events(link).await
+}
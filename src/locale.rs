@@ -0,0 +1,247 @@
+//! Time zone, clock and language of the printed tables.
+use chrono::{DateTime, Datelike, TimeZone, Weekday};
+use chrono_tz::Europe::Berlin;
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::str::FromStr;
+
+use crate::competition::ZoneKind;
+use crate::SoccerError;
+
+/// Language of the weekdays, relative dates and column headers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Language {
+    #[default]
+    #[serde(rename = "en")]
+    English,
+    #[serde(rename = "de")]
+    German,
+}
+
+impl Language {
+    /// The language of the `LC_ALL`, `LC_MESSAGES` or `LANG` locale, if it is one of ours.
+    pub fn from_env() -> Option<Self> {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|var| env::var(var).ok())
+            .find(|value| !value.is_empty())
+            .and_then(|value| value.get(..2)?.parse().ok())
+    }
+}
+
+impl FromStr for Language {
+    type Err = SoccerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "en" | "english" => Ok(Language::English),
+            "de" | "german" | "deutsch" => Ok(Language::German),
+            other => Err(SoccerError::InvalidArgument(format!(
+                "unknown language '{other}', expected en or de"
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Clock {
+    #[default]
+    #[serde(rename = "24")]
+    H24,
+    #[serde(rename = "12")]
+    H12,
+}
+
+impl FromStr for Clock {
+    type Err = SoccerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim_end_matches('h') {
+            "24" => Ok(Clock::H24),
+            "12" => Ok(Clock::H12),
+            other => Err(SoccerError::InvalidArgument(format!(
+                "unknown clock '{other}', expected 12 or 24"
+            ))),
+        }
+    }
+}
+
+/// Parses an IANA time zone name such as `Europe/London`.
+pub fn parse_tz(name: &str) -> Result<Tz, SoccerError> {
+    name.parse()
+        .map_err(|_| SoccerError::InvalidArgument(format!("unknown time zone '{name}'")))
+}
+
+/// The zone named by the `TZ` variable. Values that are no IANA name, like file paths, are
+/// ignored.
+pub fn tz_from_env() -> Option<Tz> {
+    let tz = env::var("TZ").ok()?;
+    tz.trim_start_matches(':').parse().ok()
+}
+
+/// How times and headers are printed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Locale {
+    pub tz: Tz,
+    pub clock: Clock,
+    pub language: Language,
+}
+
+impl Default for Locale {
+    /// German time, 24h clock, English words: what `soccer` always printed.
+    fn default() -> Self {
+        Locale {
+            tz: Berlin,
+            clock: Clock::H24,
+            language: Language::English,
+        }
+    }
+}
+
+impl Locale {
+    /// The time of day of `time` in this locale's zone, e.g. `15:30` or `3:30 PM`.
+    pub fn time<Z: TimeZone>(&self, time: &DateTime<Z>) -> String {
+        let time = time.with_timezone(&self.tz);
+        match self.clock {
+            Clock::H24 => time.format("%H:%M").to_string(),
+            Clock::H12 => time.format("%-I:%M %p").to_string(),
+        }
+    }
+
+    /// The date of `time` in this locale's zone with its weekday, the year only if it differs
+    /// from `this_year`.
+    pub fn date<Z: TimeZone>(&self, time: &DateTime<Z>, this_year: i32) -> String {
+        let time = time.with_timezone(&self.tz);
+        let weekday = self.weekday(time.weekday());
+        let with_year = time.year() != this_year;
+        match (self.language, with_year) {
+            (Language::English, false) => format!("{weekday} {}", time.format("%-d %b")),
+            (Language::English, true) => format!("{weekday} {}", time.format("%-d %b %Y")),
+            (Language::German, false) => format!("{weekday} {}", time.format("%d.%m.")),
+            (Language::German, true) => format!("{weekday} {}", time.format("%d.%m.%Y")),
+        }
+    }
+
+    pub fn weekday(&self, weekday: Weekday) -> &'static str {
+        let names = match self.language {
+            Language::English => ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+            Language::German => ["Mo", "Di", "Mi", "Do", "Fr", "Sa", "So"],
+        };
+        names[weekday.num_days_from_monday() as usize]
+    }
+
+    pub fn zone_name(&self, zone: ZoneKind) -> &'static str {
+        match (self.language, zone) {
+            (Language::English, ZoneKind::ChampionsLeague) => "Champions League",
+            (Language::English, ZoneKind::EuropaLeague) => "Europa League",
+            (Language::English, ZoneKind::ConferenceLeague) => "Conference League",
+            (Language::English, ZoneKind::Promotion) => "Promotion",
+            (Language::English, ZoneKind::PromotionPlayoff) => "Promotion play-off",
+            (Language::English, ZoneKind::RelegationPlayoff) => "Relegation play-off",
+            (Language::English, ZoneKind::Relegation) => "Relegation",
+            (Language::English, ZoneKind::RoundOf16) => "Round of 16",
+            (Language::English, ZoneKind::KnockoutPlayoff) => "Knockout play-offs",
+            (Language::German, ZoneKind::ChampionsLeague) => "Champions League",
+            (Language::German, ZoneKind::EuropaLeague) => "Europa League",
+            (Language::German, ZoneKind::ConferenceLeague) => "Conference League",
+            (Language::German, ZoneKind::Promotion) => "Aufstieg",
+            (Language::German, ZoneKind::PromotionPlayoff) => "Aufstiegsrelegation",
+            (Language::German, ZoneKind::RelegationPlayoff) => "Relegation",
+            (Language::German, ZoneKind::Relegation) => "Abstieg",
+            (Language::German, ZoneKind::RoundOf16) => "Achtelfinale",
+            (Language::German, ZoneKind::KnockoutPlayoff) => "Play-offs",
+        }
+    }
+
+    /// The name of a matchday, e.g. `Matchday 7` or `7. Spieltag`.
+    pub fn matchday(&self, matchday: u32) -> String {
+        match self.language {
+            Language::English => format!("Matchday {matchday}"),
+            Language::German => format!("{matchday}. Spieltag"),
+        }
+    }
+
+    pub fn words(&self) -> &'static Words {
+        match self.language {
+            Language::English => &ENGLISH,
+            Language::German => &GERMAN,
+        }
+    }
+}
+
+/// Everything printed in words, in one language.
+pub struct Words {
+    pub yesterday: &'static str,
+    pub today: &'static str,
+    pub tomorrow: &'static str,
+    pub now: &'static str,
+    pub scores_header: [&'static str; 4],
+    /// Titles of the standings columns, in the order of [`Column::ALL`](crate::standings::Column::ALL).
+    pub standings_header: [&'static str; 9],
+    /// Competition, matchday, date, venue, opponent, score and outcome.
+    pub fixtures_header: [&'static str; 7],
+    /// Home and away.
+    pub venues: [&'static str; 2],
+    /// Win, draw and loss.
+    pub outcomes: [&'static str; 3],
+    pub form: &'static str,
+    pub next_game: &'static str,
+    pub last_game: &'static str,
+    /// Minute, team, event and player of the list of match events.
+    pub events_header: [&'static str; 4],
+    /// Names of the match events, in the order of [`MatchEventKind`](crate::MatchEventKind).
+    pub event_kinds: [&'static str; 8],
+}
+
+const ENGLISH: Words = Words {
+    yesterday: "Yesterday",
+    today: "Today",
+    tomorrow: "Tomorrow",
+    now: "now",
+    scores_header: ["Home", "", "Away", "Time"],
+    standings_header: ["#", "Team", "GP", "W", "D", "L", "Goals", "GD", "P"],
+    fixtures_header: ["Competition", "MD", "Date", "", "Opponent", "Score", ""],
+    venues: ["H", "A"],
+    outcomes: ["W", "D", "L"],
+    form: "Form",
+    next_game: "Next",
+    last_game: "Last",
+    events_header: ["Min.", "Team", "Event", "Player"],
+    event_kinds: [
+        "Goal",
+        "Own goal",
+        "Penalty",
+        "Missed penalty",
+        "Yellow card",
+        "Second yellow",
+        "Red card",
+        "Substitution",
+    ],
+};
+
+const GERMAN: Words = Words {
+    yesterday: "Gestern",
+    today: "Heute",
+    tomorrow: "Morgen",
+    now: "jetzt",
+    scores_header: ["Heim", "", "Gast", "Zeit"],
+    standings_header: ["#", "Verein", "Sp", "S", "U", "N", "Tore", "Diff", "Pkt"],
+    fixtures_header: ["Wettbewerb", "Sp.", "Datum", "", "Gegner", "Erg.", ""],
+    venues: ["H", "A"],
+    outcomes: ["S", "U", "N"],
+    form: "Form",
+    next_game: "Nächstes Spiel",
+    last_game: "Letztes Spiel",
+    events_header: ["Min.", "Verein", "Ereignis", "Spieler"],
+    event_kinds: [
+        "Tor",
+        "Eigentor",
+        "Elfmeter",
+        "Elfmeter verschossen",
+        "Gelbe Karte",
+        "Gelb-Rot",
+        "Rote Karte",
+        "Wechsel",
+    ],
+};
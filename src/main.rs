@@ -1,609 +1,676 @@
-use chrono::{DateTime, Datelike, Timelike, Utc};
-use chrono_tz::{Europe::Berlin, Tz};
-use core::f32;
-use prettytable::{cell, format, row, Table};
-use reqwest::Error;
-use scraper::{selectable::Selectable, ElementRef, Html, Selector};
+use chrono::Utc;
+use chrono_tz::Europe::Berlin;
+use chrono_tz::Tz;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use futures::{stream, StreamExt};
+use soccer::cache::Cache;
+use soccer::competition::{self, Season, COMPETITIONS};
+use soccer::events;
+use soccer::fuzzy::Favourites;
+use soccer::locale::{parse_tz, tz_from_env, Clock, Language};
+use soccer::output::{
+    emit_competitions, emit_dashboard, emit_detailed_scores, emit_events, emit_fixtures,
+    emit_lineups, emit_matchday, emit_scores, emit_standings, EventWriter,
+};
+use soccer::provider::{find_games, match_link, CONCURRENT_PAGES};
+use soccer::render::{print_cache_stats, time_label, Layout, Settings};
+use soccer::schedule;
+use soccer::standings::{self, Column, TableView};
+use soccer::theme::{self, Theme, THEMES};
+use soccer::watch::{watch, View};
+use soccer::{
+    Competition, Config, DataProvider, Fixtures, Format, Game, Locale, MatchEvent, MatchStatus,
+    SoccerError, Sportschau, Team,
+};
 use std::env;
-mod fuzzy;
-
-////////////////////////////////////////////////////////////////////////////////////////////////////
-#[derive(Debug)]
-pub struct Game {
-    home: String,
-    away: String,
-    score_home: usize,
-    score_away: usize,
-    timestamp: DateTime<Tz>,
-    status: String,
-    link: String,
+use std::io::{self, ErrorKind, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::str::FromStr;
+
+/// Scores, standings and lineups from sportschau.de in your terminal.
+#[derive(Parser)]
+#[command(name = "soccer", version, propagate_version = true)]
+struct Cli {
+    /// Competition to show, see `soccer competitions` for the available ids [default: bundesliga]
+    #[arg(short, long, global = true, value_name = "ID", value_parser = competition::find)]
+    competition: Option<&'static Competition>,
+
+    /// Output format: table, json, ndjson or csv [default: table]
+    #[arg(long, global = true, value_parser = Format::from_str)]
+    format: Option<Format>,
+
+    /// Read saved sportschau pages from this directory instead of the network
+    #[arg(long, global = true, value_name = "DIR", env = "SOCCER_FIXTURES")]
+    fixtures: Option<PathBuf>,
+
+    /// Serve every page from the cache, however old, and never touch the network
+    #[arg(long, global = true)]
+    offline: bool,
+
+    /// Time zone of the printed times, e.g. Europe/London [default: $TZ or Europe/Berlin]
+    #[arg(long, global = true, value_name = "ZONE", value_parser = parse_tz)]
+    tz: Option<Tz>,
+
+    /// 12 or 24 hour clock [default: 24]
+    #[arg(long, global = true, value_parser = Clock::from_str)]
+    clock: Option<Clock>,
+
+    /// Language of weekdays and column headers, en or de [default: from $LANG, else en]
+    #[arg(long, global = true, value_name = "LANG", value_parser = Language::from_str)]
+    lang: Option<Language>,
+
+    /// Favourite team, printed in bold; repeat for several [default: favourites of the config]
+    #[arg(long = "team", global = true, value_name = "NAME")]
+    teams: Vec<String>,
+
+    /// Only show the games of the favourite teams
+    #[arg(long, global = true)]
+    only_favourites: bool,
+
+    /// Colour theme, dark or light [default: dark]
+    #[arg(long, global = true, value_name = "NAME", value_parser = theme::find)]
+    theme: Option<&'static Theme>,
+
+    /// When to use colours; auto leaves them out if NO_COLOR is set or the output is no terminal
+    #[arg(long, global = true, value_name = "WHEN", default_value = "auto")]
+    color: ColorChoice,
+
+    /// Print full team names and every column, however narrow the terminal
+    #[arg(long, global = true, conflicts_with = "compact")]
+    wide: bool,
+
+    /// Print abbreviated team names and only the essential columns
+    #[arg(long, global = true)]
+    compact: bool,
+
+    /// Config file to read instead of the default one, see `soccer config path`
+    #[arg(long, global = true, value_name = "FILE", env = "SOCCER_CONFIG")]
+    config: Option<PathBuf>,
+
+    #[command(subcommand)]
+    command: Option<Command>,
 }
-struct Team {
-    standing: String,
-    name: String,
-    short: String,
-    abbrev: String,
-    games: String,
-    wins: String,
-    draws: String,
-    losses: String,
-    goals: String,
-    goal_dif: String,
-    points: String,
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ColorChoice {
+    Auto,
+    Always,
+    Never,
 }
 
-#[derive(Debug)]
-pub struct LineUp {
-    team: String,
-    players: Vec<Player>,
+/// Which matchday `soccer scores` shows.
+#[derive(Args, Default)]
+struct Round {
+    /// Show this matchday instead of the current one
+    #[arg(
+        long,
+        value_name = "N",
+        value_parser = clap::value_parser!(u32).range(1..),
+        conflicts_with_all = ["prev", "next"]
+    )]
+    matchday: Option<u32>,
+    /// Show the matchday before the current one
+    #[arg(long, conflicts_with = "next")]
+    prev: bool,
+    /// Show the matchday after the current one
+    #[arg(long)]
+    next: bool,
+    /// Season, e.g. 2023/24; without --matchday its last matchday
+    #[arg(long, value_parser = Season::from_str)]
+    season: Option<Season>,
 }
-impl LineUp {
-    fn new() -> Self {
-        LineUp {
-            team: String::new(),
-            players: Vec::new(),
-        }
-    }
+
+#[derive(Subcommand)]
+enum Command {
+    /// Displays the current scores (default)
+    Scores {
+        #[command(flatten)]
+        round: Round,
+        /// Show the goal scorers under every game
+        #[arg(long)]
+        detailed: bool,
+    },
+    /// Displays the current standings
+    Standings {
+        /// Games counted: overall, home, away, first-half or second-half
+        #[arg(long, value_name = "VIEW", default_value = "overall", value_parser = TableView::from_str)]
+        view: TableView,
+        /// Sort by pos, team, games, wins, draws, losses, goals, diff or points
+        #[arg(long, value_name = "COLUMN", value_parser = Column::from_str)]
+        sort: Option<Column>,
+        /// Comma-separated columns of the table format, e.g. pos,team,points [default: as many as fit]
+        #[arg(long, value_name = "COLUMNS", value_delimiter = ',', value_parser = Column::from_str)]
+        columns: Vec<Column>,
+        /// The table as it stands, with the current scores of the live games counted
+        #[arg(long, conflicts_with = "view")]
+        live: bool,
+    },
+    /// Displays the current scores and standings
+    Matchday,
+    /// Displays the lineups of a team's current match
+    Match {
+        /// Team name, fuzzy matched against the current games
+        #[arg(required = true, num_args = 1..)]
+        query: Vec<String>,
+        /// Take the best match without asking when several games match equally well
+        #[arg(long)]
+        first: bool,
+        /// Show the goals, cards and substitutions on a timeline instead of the lineups
+        #[arg(long)]
+        events: bool,
+    },
+    /// Shows one club: its place in the table, form, next and last game and the last lineups
+    #[command(subcommand_precedence_over_arg = true)]
+    Team {
+        /// Club name, fuzzy matched against the teams of the competition
+        #[arg(required = true, num_args = 1..)]
+        query: Vec<String>,
+        #[command(subcommand)]
+        view: Option<TeamView>,
+    },
+    /// Lists the available competitions
+    Competitions,
+    /// Waits for the next goals, kick-offs and final whistles and prints them
+    Events {
+        /// Keep printing events instead of stopping after the first ones
+        #[arg(short, long)]
+        follow: bool,
+    },
+    /// Keeps scores, standings or a match on screen and refreshes them while games are live
+    Watch {
+        #[command(subcommand)]
+        view: Option<WatchView>,
+    },
+    /// Inspects or empties the cache of downloaded pages
+    Cache {
+        #[command(subcommand)]
+        action: CacheAction,
+    },
+    /// Shows or changes the settings in the config file
+    Config {
+        #[command(subcommand)]
+        action: ConfigAction,
+    },
+}
+
+#[derive(Subcommand)]
+enum WatchView {
+    /// Watches the current scores (default)
+    Scores,
+    /// Watches the current standings
+    Standings,
+    /// Watches the lineups and score of a team's current match
+    Match {
+        /// Team name, fuzzy matched against the current games
+        #[arg(required = true, num_args = 1..)]
+        query: Vec<String>,
+        /// Take the best match without asking when several games match equally well
+        #[arg(long)]
+        first: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum TeamView {
+    /// Lists every game of the season with its result or kickoff
+    Fixtures {
+        /// Also list the games in the other competitions the club plays in, e.g. cup and Europe
+        #[arg(long)]
+        all_competitions: bool,
+    },
 }
 
-#[derive(Debug)]
-struct Player {
-    x_pos: f32,
-    y_pos: f32,
-    name: String,
-    number: String,
+#[derive(Subcommand)]
+enum CacheAction {
+    /// Shows where the cache is and how much it holds
+    Stats,
+    /// Deletes every cached page
+    Clear,
+}
+
+#[derive(Subcommand)]
+enum ConfigAction {
+    /// Prints the settings in effect, the config file with the SOCCER_* variables applied
+    Show,
+    /// Changes a setting in the config file, an empty value removes it
+    Set {
+        /// One of competition, favourites, tz, clock, lang, theme, format or cache.<ttl>
+        key: String,
+        /// New value; favourites are separated by commas, cache TTLs are seconds
+        value: String,
+    },
+    /// Prints where the config file is
+    Path,
 }
-////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[tokio::main]
-async fn main() -> Result<(), Error> {
-    const LINKS:[&'static str;2] = [
-        "https://www.sportschau.de/live-und-ergebnisse/fussball/deutschland-bundesliga/spiele-und-ergebnisse",
-        "https://www.sportschau.de/live-und-ergebnisse/fussball/uefa-champions-league/spiele-und-ergebnisse",
-    ];
-    let mut args: Vec<String> = env::args().collect();
-    let mut competition: usize = 0;
-    if args.len() > 1 {
-        match args[1].as_str() {
-            "-c" => {
-                competition = 1;
-                args = args[1..].to_vec();
-            }
-            _ => {}
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match run(cli).await {
+        Ok(()) => ExitCode::SUCCESS,
+        // The output was piped into a program that stopped reading, e.g. `head`.
+        Err(SoccerError::Io(e)) if e.kind() == ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::from(e.exit_code())
+        }
+    }
+}
+
+async fn run(cli: Cli) -> Result<(), SoccerError> {
+    let colors = match cli.color {
+        ColorChoice::Always => true,
+        ColorChoice::Never => false,
+        ColorChoice::Auto => {
+            io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none_or(|v| v.is_empty())
         }
-        if args.len() > 1 {
-            match args[1].as_str() {
-                "standings" => {
-                    let standings = gather_standings(&LINKS[competition]).await.unwrap();
-                    print_standings(standings);
+    };
+    colored::control::set_override(colors);
+    let config_path = cli.config.clone().or_else(Config::default_path);
+    let default = Command::Scores {
+        round: Round::default(),
+        detailed: false,
+    };
+    let command = cli.command.unwrap_or(default);
+    // `soccer config` works on the file itself, so that a broken file or `SOCCER_` variable can
+    // still be looked at and fixed.
+    let config = match command {
+        Command::Config { .. } => Config::default(),
+        _ => load_config(config_path.as_deref())?,
+    };
+    let locale = Locale {
+        tz: cli.tz.or(config.tz).or_else(tz_from_env).unwrap_or(Berlin),
+        clock: cli.clock.or(config.clock).unwrap_or_default(),
+        language: cli
+            .lang
+            .or(config.lang)
+            .or_else(Language::from_env)
+            .unwrap_or_default(),
+    };
+    let provider: Box<dyn DataProvider> = match (cli.fixtures, Cache::default_dir().map(Cache::new))
+    {
+        (Some(dir), _) => Box::new(Fixtures::new(dir)),
+        (None, Some(cache)) => Box::new(
+            Sportschau::new()
+                .with_cache(cache, config.cache)
+                .offline(cli.offline),
+        ),
+        (None, None) => Box::new(Sportschau::new().offline(cli.offline)),
+    };
+    let provider = provider.as_ref();
+    let competition = match (cli.competition, &config.competition) {
+        (Some(competition), _) => competition,
+        (None, Some(id)) => competition::find(id)?,
+        (None, None) => &COMPETITIONS[0],
+    };
+    let format = cli.format.or(config.format).unwrap_or_default();
+    let favourites = if cli.teams.is_empty() {
+        config.favourites
+    } else {
+        cli.teams
+    };
+    if cli.only_favourites && favourites.is_empty() {
+        return Err(SoccerError::InvalidArgument(
+            "--only-favourites needs favourites, pass --team or run `soccer config set favourites`"
+                .into(),
+        ));
+    }
+    let theme = match (cli.theme, &config.theme) {
+        (Some(theme), _) => theme,
+        (None, Some(name)) => theme::find(name)?,
+        (None, None) => &THEMES[0],
+    };
+    let settings = Settings {
+        locale,
+        favourites: Favourites::new(&favourites),
+        theme,
+        layout: if cli.wide {
+            Layout::Wide
+        } else if cli.compact {
+            Layout::Compact
+        } else {
+            terminal_width().map_or(Layout::Wide, Layout::Fit)
+        },
+    };
+    let only_favourites = |games: Vec<Game>| -> Vec<Game> {
+        if !cli.only_favourites {
+            return games;
+        }
+        games
+            .into_iter()
+            .filter(|game| settings.favourites.plays_in(game))
+            .collect()
+    };
+    let scores_url = competition.scores_url();
+
+    match command {
+        Command::Scores { round, detailed } => {
+            let navigating = round.matchday.is_some() || round.prev || round.next;
+            let scores = round_scores(provider, competition, &round).await?;
+            if format == Format::Table && (navigating || round.season.is_some()) {
+                if let Some(matchday) = scores.iter().find_map(|game| game.matchday) {
+                    let heading = settings.locale.matchday(matchday);
+                    match round.season {
+                        Some(season) => writeln!(io::stdout(), "{heading}, {season}")?,
+                        None => writeln!(io::stdout(), "{heading}")?,
+                    }
                 }
-                "scores" => {
-                    let scores = gather_scores(&LINKS[competition]).await.unwrap();
-                    print_scores(scores);
+            }
+            let scores = only_favourites(scores);
+            // CSV has no room for the events, so their pages are not even fetched.
+            if detailed && format != Format::Csv {
+                let events = match_events(provider, &scores).await;
+                emit_detailed_scores(scores, events, format, &settings)?;
+            } else {
+                emit_scores(scores, format, &settings)?;
+            }
+        }
+        Command::Standings {
+            view,
+            sort,
+            columns,
+            live,
+        } => {
+            if !columns.is_empty() && format != Format::Table {
+                return Err(SoccerError::InvalidArgument(
+                    "--columns only applies to the table format, the others have every field"
+                        .into(),
+                ));
+            }
+            let table_url = table_url(competition)?;
+            let mut standings = if live {
+                let (scores, official) =
+                    tokio::join!(provider.scores(&scores_url), provider.standings(&table_url));
+                let (scores, official) = (scores?, official?);
+                warn_inconsistent(&official);
+                if !scores.iter().any(|game| game.status.is_live()) {
+                    eprintln!("note: no game is live, this is the official table");
                 }
-                "match" => {
-                    if args.len() > 2 {
-                        let query = args[2].to_string();
-                        let selected_match = get_lineup_link(query, &LINKS[competition]);
-                        let stadium = construct_stadium();
-                        let selected_match = selected_match.await?;
-                        let lineups = get_lineup(selected_match).await?;
-                        let _ = populate_stadium(lineups, stadium);
+                standings::project(&official, &scores, competition.tiebreakers)
+            } else {
+                let standings = standings::fetch(provider, competition, &table_url, view).await?;
+                warn_inconsistent(&standings);
+                standings
+            };
+            if let Some(column) = sort {
+                standings::sort(&mut standings, column);
+            }
+            // The zones are those of the overall table.
+            let zones = if view == TableView::Overall {
+                competition.zones
+            } else {
+                &[]
+            };
+            emit_standings(standings, zones, &columns, format, &settings)?;
+        }
+        Command::Matchday => {
+            let table_url = table_url(competition)?;
+            let (scores, standings) =
+                tokio::join!(provider.scores(&scores_url), provider.standings(&table_url));
+            let standings = standings?;
+            warn_inconsistent(&standings);
+            emit_matchday(
+                only_favourites(scores?),
+                standings,
+                competition.zones,
+                format,
+                &settings,
+            )?;
+        }
+        Command::Match {
+            query,
+            first,
+            events,
+        } => {
+            if !events && !competition.features.lineups {
+                return Err(SoccerError::InvalidArgument(format!(
+                    "sportschau has no lineups for the {}",
+                    competition.name
+                )));
+            }
+            let query = query.join(" ");
+            let candidates = find_games(provider, &query, &scores_url).await?;
+            let game = pick_game(&query, candidates, first, &settings.locale)?;
+            let selected_match = match_link(&game)?;
+            if events {
+                let events = provider.events(&selected_match).await?;
+                emit_events(&game, &events, format, &settings)?;
+            } else {
+                let lineups = provider.lineup(&selected_match).await?;
+                emit_lineups(lineups, format)?;
+            }
+        }
+        Command::Team { query, view: None } => {
+            let query = query.join(" ");
+            let dashboard = schedule::dashboard(provider, competition, &query).await?;
+            emit_dashboard(&dashboard, competition.zones, format, &settings)?;
+        }
+        Command::Team {
+            query,
+            view: Some(TeamView::Fixtures { all_competitions }),
+        } => {
+            let query = query.join(" ");
+            let (club, fixtures) =
+                schedule::fixtures(provider, competition, &query, all_competitions).await?;
+            emit_fixtures(&club, &fixtures, format, &settings)?;
+        }
+        Command::Competitions => {
+            emit_competitions(COMPETITIONS, format)?;
+        }
+        Command::Events { follow } => {
+            let mut writer = EventWriter::new(format, &settings.locale);
+            // Events detected in the same poll arrive together, a batch is one poll.
+            let mut batches =
+                std::pin::pin!(events::follow(provider, &scores_url).ready_chunks(64));
+            while let Some(batch) = batches.next().await {
+                for event in batch {
+                    match event {
+                        Ok(event) => writer.write(&event)?,
+                        Err(e) if follow => eprintln!("error: {e}"),
+                        Err(e) => return Err(e),
                     }
                 }
-                "matchday" => {
-                    let scores = gather_scores(&LINKS[competition]);
-                    let standings = gather_standings(&LINKS[competition]);
-                    let scores = scores.await.unwrap();
-                    let standings = standings.await.unwrap();
-                    print_scores(scores);
-                    print_standings(standings);
+                if !follow {
+                    break;
                 }
-                "--help" => {
-                    help();
+            }
+        }
+        Command::Watch { view } => {
+            let view = match view.unwrap_or(WatchView::Scores) {
+                WatchView::Scores => View::Scores,
+                WatchView::Standings => View::Standings,
+                WatchView::Match { query, first } => {
+                    let query = query.join(" ");
+                    let candidates = find_games(provider, &query, &scores_url).await?;
+                    let game = pick_game(&query, candidates, first, &settings.locale)?;
+                    View::Match(match_link(&game)?)
                 }
-                "--version" => {
-                    println!("Soccer version: {}", env!("CARGO_PKG_VERSION"));
+            };
+            watch(provider, competition, view, &settings).await?;
+        }
+        Command::Cache { action } => {
+            let Some(cache) = Cache::default_dir().map(Cache::new) else {
+                return Err(SoccerError::InvalidArgument(
+                    "no cache directory available on this system".into(),
+                ));
+            };
+            match action {
+                CacheAction::Stats => {
+                    print_cache_stats(cache.dir(), &cache.stats()?, &settings.locale)?
                 }
-                _ => {
-                    println!("No such command. {}", args[1]);
-                    print!(
-                        "Available commands:\n→ table\n→ scores\n→ matchday\nDefault: scores\n\n"
-                    );
+                CacheAction::Clear => cache.clear()?,
+            }
+        }
+        Command::Config { action } => {
+            let Some(path) = config_path else {
+                return Err(SoccerError::InvalidArgument(
+                    "no config directory available on this system, pass --config".into(),
+                ));
+            };
+            match action {
+                ConfigAction::Show => {
+                    let config = load_config(Some(&path))?;
+                    write!(io::stdout(), "{}", config.to_toml()?)?;
                 }
+                ConfigAction::Set { key, value } => {
+                    let mut config = Config::load(&path)?;
+                    config.set(&key, &value)?;
+                    config.save(&path)?;
+                }
+                ConfigAction::Path => writeln!(io::stdout(), "{}", path.display())?,
             }
-        } else {
-            let scores = gather_scores(&LINKS[competition]).await.unwrap();
-            print_scores(scores);
         }
-    } else {
-        let scores = gather_scores(&LINKS[competition]).await.unwrap();
-        print_scores(scores);
     }
 
     Ok(())
 }
 
-fn help() {
-    println!("Available Commands:\n");
-    println!("soccer                    Displays the current score");
-    println!("soccer standings          Displays the current standings");
-    println!("soccer matchday           Displays the current scores and standings.");
-    println!("soccer match [team name]  Displays match for [team name]s match.");
-    println!("soccer --version          Displays current version");
-    println!("soccer --help             Displays current version");
-    println!("");
+/// The config file at `path`, if any, with the `SOCCER_` variables applied.
+fn load_config(path: Option<&Path>) -> Result<Config, SoccerError> {
+    let mut config = match path {
+        Some(path) => Config::load(path)?,
+        None => Config::default(),
+    };
+    config.apply_env()?;
+    Ok(config)
 }
-// Score Stuff
-async fn gather_scores(link: &str) -> Result<Vec<Game>, Error> {
-    let body = reqwest::get(link).await?.text().await?;
-    let document = Html::parse_document(&body);
-    let mut games: Vec<Game> = Vec::<Game>::new();
-
-    let sel_match = Selector::parse("li.match").unwrap();
-    for element in document.select(&sel_match) {
-        let sel_teams = Selector::parse("div.team-name").unwrap();
-        let sel_status = Selector::parse("div.match-status").unwrap();
-        let sel_score_home = Selector::parse("div.match-result-home").unwrap();
-        let sel_score_away = Selector::parse("div.match-result-away").unwrap();
-        let sel_minute = Selector::parse("div.current-minute").unwrap();
-        let sel_link = Selector::parse("div.match-more").unwrap();
-
-        let teams: Vec<_> = element.select(&sel_teams).collect();
-        let (home, away) = (teams[0].inner_html(), teams[1].inner_html());
-
-        let timestamp = if let Some(ts) = element.value().attr("data-datetime") {
-            let ts = ts.parse::<DateTime<Utc>>().unwrap();
-            ts.with_timezone(&Berlin)
-        } else {
-            let ts = Utc::now();
-            ts.with_timezone(&Berlin)
-        };
-
-        let score_home_element: Vec<_> = element.select(&sel_score_home).collect();
-        let score_home: usize = score_home_element
-            .get(0)
-            .and_then(|score_element| score_element.first_child())
-            .and_then(ElementRef::wrap)
-            .map(|child_element| child_element.inner_html())
-            .unwrap_or_default()
-            .parse::<usize>()
-            .unwrap_or(0);
-
-        let score_away_element: Vec<_> = element.select(&sel_score_away).collect();
-        let score_away: usize = score_away_element
-            .get(0)
-            .and_then(|score_element| score_element.first_child())
-            .and_then(ElementRef::wrap)
-            .map(|child_element| child_element.inner_html())
-            .unwrap_or_default()
-            .parse::<usize>()
-            .unwrap_or(0);
-
-        let match_status: Vec<_> = element.select(&sel_status).collect();
-        let stat = match_status[0].inner_html();
-        let status = if stat == "Beendet" {
-            String::from("OVER")
-        } else if stat == "Live" {
-            String::from(format!("LIVE"))
-        } else {
-            String::from("UPCOMING")
-        };
-
-        let mut link: String = String::new();
-        if let Some(href) = element
-            .select(&sel_link)
-            .next()
-            .and_then(|el| el.select(&Selector::parse("a").unwrap()).next())
-            .and_then(|a| a.value().attr("href"))
-        {
-            link = href.to_string();
-        }
 
-        games.push(Game {
-            home,
-            away,
-            score_home,
-            score_away,
-            timestamp,
-            status,
-            link,
+/// The goals, cards and substitutions of each of `games` that has started, in the same order.
+/// Games without a match page get none, as do those whose page cannot be read, with a warning.
+async fn match_events(provider: &dyn DataProvider, games: &[Game]) -> Vec<Vec<MatchEvent>> {
+    stream::iter(games)
+        .map(|game| async move {
+            if game.status == MatchStatus::Upcoming || game.link.is_empty() {
+                return Vec::new();
+            }
+            provider.events(&game.link).await.unwrap_or_else(|e| {
+                eprintln!("warning: no events of {} - {}: {e}", game.home, game.away);
+                Vec::new()
+            })
         })
-    }
-    Ok(games)
+        .buffered(CONCURRENT_PAGES)
+        .collect()
+        .await
 }
-fn print_scores(info: Vec<Game>) {
-    let mut table = Table::new();
-    let now = Utc::now();
-    let now = now.with_timezone(&Berlin);
-
-    table.set_format(*format::consts::FORMAT_BOX_CHARS);
-    table.add_row(row!["Home", "", "Away", "Time",]);
-    for item in info {
-        let hour_difference: i32 = item.timestamp.hour() as i32 - now.hour() as i32;
-        let date_difference: i32 = item.timestamp.day() as i32 - now.day() as i32;
-        let mut date = String::new();
-
-        if date_difference == 0 && hour_difference <= 0 {
-            date = format!("{}", item.status);
-        } else if date_difference == 0 {
-            date = format!(
-                "Today, {:2}:{:2}",
-                item.timestamp.hour(),
-                item.timestamp.minute()
-            );
-        } else if date_difference == 1 {
-            date = format!(
-                "Tomorrow, {:2}:{:2}",
-                item.timestamp.hour(),
-                item.timestamp.minute()
-            );
-        } else if date_difference > 1 {
-            date = format!(
-                "{}, {:2}:{:2}",
-                item.timestamp.weekday(),
-                item.timestamp.hour(),
-                item.timestamp.minute()
-            );
-        }
 
-        table.add_row(row![
-            cell!(item.home),
-            cell!(format!("{} - {}", item.score_home, item.score_away)),
-            cell!(item.away),
-            cell!(date)
-        ]);
-    }
-    table.printstd();
-}
-////////////////////////////////////////////////////////////////////////////////////////////////////
-
-// Standing Stuff
-async fn gather_standings(link: &str) -> Result<Vec<Team>, Error> {
-    let link: String = construct_url("", link.to_string(), "/tabelle");
-    let body = reqwest::get(link).await?.text().await?;
-    let document = Html::parse_document(&body);
-    let sel_tr = Selector::parse("tr[class^='hs_team_id-']").unwrap();
-    let mut teams: Vec<Team> = Vec::<Team>::new();
-    for item in document.select(&sel_tr) {
-        let text_content: Vec<String> = item.text().map(|s| s.to_string()).collect();
-        if text_content.len() >= 10 {
-            let mut iter = text_content.into_iter();
-            let team = Team {
-                standing: iter.next().unwrap(),
-                name: iter.next().unwrap(),
-                short: iter.next().unwrap(),
-                abbrev: iter.next().unwrap(),
-                games: iter.next().unwrap(),
-                wins: iter.next().unwrap(),
-                draws: iter.next().unwrap(),
-                losses: iter.next().unwrap(),
-                goals: iter.next().unwrap(),
-                goal_dif: iter.next().unwrap(),
-                points: iter.next().unwrap(),
-            };
-            teams.push(team);
-        }
-    }
-    Ok(teams)
-}
-fn print_standings(standings: Vec<Team>) {
-    let mut table = Table::new();
-    table.set_format(*format::consts::FORMAT_BOX_CHARS);
-    table.add_row(row!["#", "Team", "GP", "W", "D", "N", "GO", "GD", "P",]);
-    for item in standings {
-        table.add_row(row![
-            cell!(item.standing),
-            cell!(item.name),
-            cell!(item.games),
-            cell!(item.wins),
-            cell!(item.draws),
-            cell!(item.losses),
-            cell!(item.goals),
-            cell!(item.goal_dif),
-            cell!(item.points),
-        ]);
-    }
-    table.printstd();
-}
-////////////////////////////////////////////////////////////////////////////////////////////////////
-
-// Line-Up Stuff
-async fn get_lineup_link(query_string: String, comp_link: &str) -> Result<String, Error> {
-    let mut matching_games: Vec<Game> = Vec::new();
-    let mut link = String::new();
-    match gather_scores(comp_link).await {
-        Ok(games) => {
-            matching_games = fuzzy::fuz(query_string, games);
-        }
-        Err(e) => return Err(e),
+/// The games of the matchday `round` asks for. `--prev` and `--next` count from the matchday
+/// sportschau currently shows.
+async fn round_scores(
+    provider: &dyn DataProvider,
+    competition: &Competition,
+    round: &Round,
+) -> Result<Vec<Game>, SoccerError> {
+    if let Some(matchday) = round.matchday {
+        return provider
+            .scores(&competition.matchday_url(round.season, matchday))
+            .await;
     }
-    if let Some(probable) = matching_games.get(0) {
-        link = probable.link.clone();
+    let scores_url = match round.season {
+        Some(season) => competition.season_scores_url(season),
+        None => competition.scores_url(),
     };
-    return Ok(link);
-}
-async fn get_lineup(link: String) -> Result<Vec<LineUp>, Error> {
-    let mut line_ups: Vec<_> = Vec::new();
-    let mut home_lineup: LineUp = LineUp::new();
-    let mut away_lineup: LineUp = LineUp::new();
-    let url = construct_url("https://www.sportschau.de", link, "/taktische-aufstellung");
-    let body = reqwest::get(url).await?.text().await?;
-    let document = Html::parse_document(&body);
-
-    let sel_home = Selector::parse("div[class^='hs-starter home']").unwrap();
-    let sel_away = Selector::parse("div[class^='hs-starter away']").unwrap();
-    let sel_player = Selector::parse("div[class^='tactic'").unwrap();
-    let sel_home_name = Selector::parse("div.team-shortname-home").unwrap();
-    let sel_away_name = Selector::parse("div.team-shortname-away").unwrap();
-
-    let mut home_name = document.select(&sel_home_name);
-    if let Some(teamname) = home_name.next() {
-        home_lineup.team = teamname.text().collect();
-    }
-    let mut away_name = document.select(&sel_away_name);
-    if let Some(teamname) = away_name.next() {
-        away_lineup.team = teamname.text().collect();
+    let scores = provider.scores(&scores_url).await?;
+    if !(round.prev || round.next) {
+        return Ok(scores);
     }
-
-    if let Some(hl_div) = document.select(&sel_home).next() {
-        let home_lineup_div: Vec<_> = hl_div.select(&sel_player).collect();
-        for element in home_lineup_div {
-            let x_pos: f32 = element
-                .attr("data-xpos")
-                .and_then(|e| e.parse::<f32>().ok())
-                .unwrap_or(0.0);
-            let y_pos: f32 = element
-                .attr("data-ypos")
-                .and_then(|e| e.parse::<f32>().ok())
-                .unwrap_or(0.0);
-            let text_content: Vec<_> = element.text().collect();
-            let number_u8: u8 = text_content[0].parse::<u8>().unwrap_or(0);
-            let number: String = format!("{:02}", number_u8);
-            let name: String = text_content[1].to_string();
-            let player = Player {
-                x_pos,
-                y_pos,
-                name,
-                number,
-            };
-            home_lineup.players.push(player);
-        }
-    } else {
-        println!("No div found for the home lineup!")
+    let Some(current) = scores.iter().find_map(|game| game.matchday) else {
+        return Err(SoccerError::InvalidArgument(format!(
+            "sportschau numbers no matchdays for the {}, --prev and --next need them",
+            competition.name
+        )));
     };
-    if let Some(al_div) = document.select(&sel_away).next() {
-        let away_lineup_div: Vec<_> = al_div.select(&sel_player).collect();
-        for element in away_lineup_div {
-            let mut x_pos: f32 = element
-                .attr("data-xpos")
-                .and_then(|e| e.parse::<f32>().ok())
-                .unwrap_or(0.0);
-            let y_pos: f32 = element
-                .attr("data-ypos")
-                .and_then(|e| e.parse::<f32>().ok())
-                .unwrap_or(0.0);
-            x_pos = 1.0 - x_pos;
-            let text_content: Vec<_> = element.text().collect();
-            let number_u8: u8 = text_content[0].parse::<u8>().unwrap_or(0);
-            let number: String = format!("{:02}", number_u8);
-            let name: String = text_content[1].to_string();
-            let player = Player {
-                x_pos,
-                y_pos,
-                name,
-                number,
-            };
-            away_lineup.players.push(player);
-        }
+    let matchday = if round.prev {
+        current.saturating_sub(1)
     } else {
-        println!("No div found for the away lineup!")
+        current + 1
     };
-    home_lineup.players.sort_by(|p, p2| {
-        p.x_pos
-            .partial_cmp(&p2.x_pos)
-            .unwrap_or(std::cmp::Ordering::Equal)
-    });
-    away_lineup.players.sort_by(|p, p2| {
-        p.x_pos
-            .partial_cmp(&p2.x_pos)
-            .unwrap_or(std::cmp::Ordering::Equal)
-    });
-    home_lineup.players.sort_by(|p, p2| {
-        p.y_pos
-            .partial_cmp(&p2.y_pos)
-            .unwrap_or(std::cmp::Ordering::Equal)
-    });
-    away_lineup.players.sort_by(|p, p2| {
-        p.y_pos
-            .partial_cmp(&p2.y_pos)
-            .unwrap_or(std::cmp::Ordering::Equal)
-    });
-    line_ups.push(home_lineup);
-    line_ups.push(away_lineup);
-
-    Ok(line_ups)
-}
-fn construct_stadium() -> Vec<Vec<String>> {
-    let mut field: Vec<Vec<String>> = Vec::new();
-    let mid_space = " ".to_string().repeat(33);
-    let space_16 = " ".to_string().repeat(10);
-    let space_full = " ".to_string().repeat(44);
-    let border_h = "─".to_string().repeat(44);
-
-    let top: Vec<String> = format!("┌{}┬{}┐", border_h, border_h)
-        .split("")
-        .filter(|&l| !l.is_empty())
-        .map(|l| l.to_string())
-        .collect();
-
-    let mid: Vec<String> = format!("│{}│{}│", space_full, space_full)
-        .split("")
-        .filter(|&l| !l.is_empty())
-        .map(|l| l.to_string())
-        .collect();
-
-    let border16: Vec<String> = format!("├──────────┐{}│{}┌──────────┤", mid_space, mid_space)
-        .split("")
-        .filter(|&l| !l.is_empty())
-        .map(|l| l.to_string())
-        .collect();
-
-    let box16: Vec<String> = format!("│{}│{}│{}│{}│", space_16, mid_space, mid_space, space_16)
-        .split("")
-        .filter(|&l| !l.is_empty())
-        .map(|l| l.to_string())
-        .collect();
-
-    let border16c: Vec<String> = format!("├──────────┘{}│{}└──────────┤", mid_space, mid_space)
-        .split("")
-        .filter(|&l| !l.is_empty())
-        .map(|l| l.to_string())
-        .collect();
-
-    let bottom: Vec<String> = format!("└{}┴{}┘", border_h, border_h)
-        .split("")
-        .filter(|&l| !l.is_empty())
-        .map(|l| l.to_string())
-        .collect();
-
-    field.push(top);
-    for _ in 0..4 {
-        field.push(mid.clone());
+    if matchday == 0 {
+        return Err(SoccerError::InvalidArgument(
+            "there is no matchday before the first one".into(),
+        ));
     }
-    field.push(border16);
-    for _ in 0..7 {
-        field.push(box16.clone());
-    }
-    field.push(border16c);
-    for _ in 0..4 {
-        field.push(mid.clone());
+    provider
+        .scores(&competition.matchday_url(round.season, matchday))
+        .await
+}
+
+/// Warns about rows of the table whose numbers do not add up, a sign that sportschau changed
+/// its markup or has not finished updating the table.
+fn warn_inconsistent(standings: &[Team]) {
+    for message in standings.iter().flat_map(Team::inconsistencies) {
+        eprintln!("warning: {message}");
     }
-    field.push(bottom);
+}
 
-    field
+fn table_url(competition: &Competition) -> Result<String, SoccerError> {
+    competition.table_url().ok_or_else(|| {
+        SoccerError::InvalidArgument(format!("the {} has no table", competition.name))
+    })
 }
-fn populate_stadium(lineups: Vec<LineUp>, mut stadium: Vec<Vec<String>>) {
-    let width: f32 = 38.0;
-    let height: f32 = 17.0;
-    for player in &lineups[0].players {
-        let x_pos: usize = (&width * player.y_pos).floor() as usize;
-        let y_pos: usize = (&height * player.x_pos).floor() as usize;
-
-        if let Some(row_vec) = stadium.get_mut(y_pos + 1) {
-            if x_pos > 1 {
-                if let Some(first_char) = player.number.get(0..1) {
-                    row_vec[x_pos + 6] = first_char.to_string();
-                }
-                if let Some(first_char) = player.number.get(1..2) {
-                    row_vec[x_pos + 7] = first_char.to_string();
-                }
-            } else {
-                if let Some(first_char) = player.number.get(0..1) {
-                    row_vec[x_pos + 1] = first_char.to_string();
-                }
-                if let Some(first_char) = player.number.get(1..2) {
-                    row_vec[x_pos + 2] = first_char.to_string();
-                }
-            }
-        };
-    }
 
-    for player in &lineups[1].players {
-        let x_pos: usize = (&width * player.y_pos).floor() as usize;
-        let y_pos: usize = (&height * (player.x_pos)).floor() as usize;
-        if let Some(row_vec) = stadium.get_mut(y_pos + 1) {
-            let length = row_vec.len();
-            if x_pos > 1 {
-                if let Some(first_char) = player.number.get(0..1) {
-                    row_vec[&length - (x_pos + 8)] = first_char.to_string();
-                }
-                if let Some(first_char) = player.number.get(1..2) {
-                    row_vec[&length - (x_pos + 7)] = first_char.to_string();
-                }
-            } else {
-                if let Some(first_char) = player.number.get(0..1) {
-                    row_vec[&length - (x_pos + 3)] = first_char.to_string();
-                }
-                if let Some(first_char) = player.number.get(1..2) {
-                    row_vec[&length - (x_pos + 2)] = first_char.to_string();
-                }
-            }
-        };
+/// The game meant by `query`: the best candidate if it is the only one with the best score or
+/// `first` is set, otherwise the one the user picks on the terminal.
+fn pick_game(
+    query: &str,
+    mut candidates: Vec<(u32, Game)>,
+    first: bool,
+    locale: &Locale,
+) -> Result<Game, SoccerError> {
+    let best = candidates[0].0;
+    let tied = candidates
+        .iter()
+        .filter(|(score, _)| *score == best)
+        .count();
+    if first || tied == 1 {
+        return Ok(candidates.swap_remove(0).1);
+    }
+    if !(io::stdin().is_terminal() && io::stderr().is_terminal()) {
+        return Err(SoccerError::Ambiguous {
+            query: query.to_string(),
+            games: candidates[..tied]
+                .iter()
+                .map(|(_, game)| format!("{} - {}", game.home, game.away))
+                .collect(),
+        });
     }
 
-    let home_top_border = top_border(lineups[0].team.chars().count());
-    let away_top_border = top_border(lineups[1].team.chars().count());
-    let space_between: usize =
-        stadium[0].len() - home_top_border.chars().count() - away_top_border.chars().count();
-
-    let top: Vec<String> = format!(
-        "{home_top_border}{}{away_top_border}",
-        " ".repeat(space_between)
-    )
-    .split("")
-    .filter(|&l| !l.is_empty()) // Filter out empty strings
-    .map(|l| l.to_string())
-    .collect();
-    let mid: Vec<String> = format!(
-        "│ {} │{}│ {} │",
-        lineups[0].team,
-        " ".repeat(space_between),
-        lineups[1].team
-    )
-    .split("")
-    .filter(|&l| !l.is_empty()) // Filter out empty strings
-    .map(|l| l.to_string())
-    .collect();
-    stadium.insert(0, mid);
-    stadium.insert(0, top);
-    if let Some(row_vec) = stadium.get_mut(2) {
-        let length = row_vec.len() - 1;
-        row_vec[0] = "├".to_string();
-        row_vec[home_top_border.chars().count() - 1] = "┴".to_string();
-        row_vec[length - away_top_border.chars().count() + 1] = "┴".to_string();
-        row_vec[length] = "┤".to_string();
-    };
-
-    let mut player_name_table: Vec<String> = Vec::new();
-    const PADDING_WIDTH: usize = 5;
-    let padding = " ".repeat(PADDING_WIDTH);
-    for (player1, player2) in lineups[0].players.iter().zip(lineups[1].players.iter()) {
-        let spaces_right = " ".repeat(
-            stadium[0].len()
-                - player1.name.chars().count()
-                - player2.name.chars().count()
-                - 8
-                - 2 * PADDING_WIDTH,
-        );
-        let line: String = format!(
-            "{}{}  {}{}{}  {}{}",
-            padding,
-            player1.number,
-            player1.name,
-            spaces_right,
-            player2.name,
-            player2.number,
-            padding
+    let now = Utc::now();
+    eprintln!("'{query}' matches several games:");
+    for (i, (score, game)) in candidates.iter().enumerate() {
+        eprintln!(
+            "{:>2}) {} - {}  {}  (score {score})",
+            i + 1,
+            game.home,
+            game.away,
+            time_label(game, now, locale)
         );
-        player_name_table.push(line);
-    }
-    for line in stadium {
-        println!("{}", line.join(""));
     }
-    for line in player_name_table {
-        println!("{line}");
+    let mut line = String::new();
+    loop {
+        eprint!("Which one? [1-{}, default 1] ", candidates.len());
+        line.clear();
+        if io::stdin().read_line(&mut line)? == 0 {
+            return Err(SoccerError::InvalidArgument("no game picked".into()));
+        }
+        let choice = match line.trim() {
+            "" => Some(1),
+            choice => choice.parse::<usize>().ok(),
+        };
+        if let Some(choice) = choice.filter(|choice| (1..=candidates.len()).contains(choice)) {
+            return Ok(candidates.swap_remove(choice - 1).1);
+        }
     }
 }
-fn top_border(len: usize) -> String {
-    format!("╭{}╮", "─".repeat(len + 2))
-}
-fn construct_url(base: &str, link: String, segment: &str) -> String {
-    let parts: Vec<&str> = link.rsplit("/").collect();
-    if parts.is_empty() {
-        return link;
+
+/// Width of the terminal from `$COLUMNS`, else of the terminal on stdout. `None` when printing
+/// into a pipe or file, where nothing wraps, whatever `$COLUMNS` says.
+fn terminal_width() -> Option<usize> {
+    if !io::stdout().is_terminal() {
+        return None;
     }
-    let base_url = &link[..link.len() - parts[0].len() - 1];
-    return format!("{base}{base_url}{segment}");
+    env::var("COLUMNS")
+        .ok()
+        .and_then(|columns| columns.parse().ok())
+        .or_else(|| terminal_size::terminal_size().map(|(width, _)| width.0 as usize))
 }
-////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,299 @@
+use chrono::DateTime;
+use chrono_tz::Tz;
+use serde::Serialize;
+use std::fmt;
+
+/// A single fixture of the current matchday.
+#[derive(Debug, Serialize)]
+pub struct Game {
+    pub home: String,
+    pub away: String,
+    pub score_home: usize,
+    pub score_away: usize,
+    /// Kickoff, converted to `Europe/Berlin`.
+    pub timestamp: DateTime<Tz>,
+    /// Whether the page gave the kickoff time; if not, `timestamp` is the time the page was read.
+    pub kickoff_known: bool,
+    pub status: MatchStatus,
+    /// Minute of play as shown by sportschau, e.g. `67` or `90+3`, while the game is running.
+    pub minute: Option<String>,
+    /// Path of the match page on sportschau.de, empty if the page has none yet.
+    pub link: String,
+    /// Number of the matchday, unless sportschau names the round otherwise, e.g. in cups.
+    pub matchday: Option<u32>,
+    /// sportschau's shorter names of the home side, used where the full name does not fit.
+    #[serde(skip)]
+    pub home_names: ShortNames,
+    /// sportschau's shorter names of the away side.
+    #[serde(skip)]
+    pub away_names: ShortNames,
+}
+#[cfg(test)]
+impl Game {
+    /// `home` against `away` on a Saturday afternoon, for the tests of every module.
+    pub(crate) fn test(home: &str, away: &str, score: (usize, usize), status: MatchStatus) -> Self {
+        use chrono::TimeZone;
+        use chrono_tz::Europe::Berlin;
+        Game {
+            home: home.to_string(),
+            away: away.to_string(),
+            score_home: score.0,
+            score_away: score.1,
+            timestamp: Berlin.with_ymd_and_hms(2024, 10, 19, 15, 30, 0).unwrap(),
+            kickoff_known: true,
+            status,
+            minute: None,
+            link: String::new(),
+            matchday: None,
+            home_names: ShortNames::default(),
+            away_names: ShortNames::default(),
+        }
+    }
+}
+
+/// The shorter names sportschau gives a club next to its full one, e.g. `Dortmund` and `BVB`,
+/// each empty where the page has none.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ShortNames {
+    pub short: String,
+    pub abbrev: String,
+}
+
+/// Where a game stands, serialized as e.g. `LIVE` or `HALF_TIME`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MatchStatus {
+    Upcoming,
+    Live,
+    HalfTime,
+    ExtraTime,
+    Penalties,
+    Over,
+    /// Called off or moved to another date.
+    Postponed,
+}
+impl MatchStatus {
+    /// Maps the text of sportschau's status badge, anything unknown counts as upcoming.
+    pub fn from_sportschau(text: &str) -> Self {
+        let text = text.trim().to_lowercase();
+        match text.as_str() {
+            "beendet" => MatchStatus::Over,
+            "live" => MatchStatus::Live,
+            "halbzeit" => MatchStatus::HalfTime,
+            "verlängerung" => MatchStatus::ExtraTime,
+            "elfmeterschießen" => MatchStatus::Penalties,
+            _ if text.contains("abgesagt") || text.contains("verlegt") => MatchStatus::Postponed,
+            _ => MatchStatus::Upcoming,
+        }
+    }
+    /// Whether the game is being played right now, breaks included.
+    pub fn is_live(self) -> bool {
+        matches!(
+            self,
+            MatchStatus::Live
+                | MatchStatus::HalfTime
+                | MatchStatus::ExtraTime
+                | MatchStatus::Penalties
+        )
+    }
+    /// Whether nothing is going to change about the game any more.
+    pub fn is_final(self) -> bool {
+        matches!(self, MatchStatus::Over | MatchStatus::Postponed)
+    }
+}
+impl fmt::Display for MatchStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            MatchStatus::Upcoming => "UPCOMING",
+            MatchStatus::Live => "LIVE",
+            MatchStatus::HalfTime => "HT",
+            MatchStatus::ExtraTime => "AET",
+            MatchStatus::Penalties => "PEN",
+            MatchStatus::Over => "OVER",
+            MatchStatus::Postponed => "POSTPONED",
+        };
+        f.write_str(label)
+    }
+}
+
+/// A row of the league table, as printed by sportschau.
+#[derive(Debug, Clone, Serialize)]
+pub struct Team {
+    /// Sportschau's id of the team, from the `hs_team_id-` class of its row.
+    pub id: u32,
+    pub standing: u32,
+    pub name: String,
+    pub short: String,
+    pub abbrev: String,
+    pub games: u32,
+    pub wins: u32,
+    pub draws: u32,
+    pub losses: u32,
+    pub goals_for: u32,
+    pub goals_against: u32,
+    pub goal_dif: i32,
+    pub points: u32,
+    /// Places gained (positive) or lost since the official table, only in projected tables.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub change: Option<i32>,
+}
+impl Team {
+    /// Goals for and against, e.g. `25:6`.
+    pub fn goals(&self) -> String {
+        format!("{}:{}", self.goals_for, self.goals_against)
+    }
+
+    /// Where the numbers of the row contradict each other, one message each.
+    pub fn inconsistencies(&self) -> Vec<String> {
+        let mut found = Vec::new();
+        if self.points != 3 * self.wins + self.draws {
+            found.push(format!(
+                "{} has {} points, but {} wins and {} draws make {}",
+                self.name,
+                self.points,
+                self.wins,
+                self.draws,
+                3 * self.wins + self.draws
+            ));
+        }
+        if self.games != self.wins + self.draws + self.losses {
+            found.push(format!(
+                "{} played {} games, but has {} wins, {} draws and {} losses",
+                self.name, self.games, self.wins, self.draws, self.losses
+            ));
+        }
+        if i64::from(self.goal_dif) != i64::from(self.goals_for) - i64::from(self.goals_against) {
+            found.push(format!(
+                "{} has a goal difference of {}, but goals of {}",
+                self.name,
+                self.goal_dif,
+                self.goals()
+            ));
+        }
+        found
+    }
+}
+
+/// The starting eleven of one side of a match.
+#[derive(Debug, Serialize)]
+pub struct LineUp {
+    /// Short name of the team.
+    pub team: String,
+    /// Players ordered from the goalkeeper forwards.
+    pub players: Vec<Player>,
+}
+impl LineUp {
+    pub fn new() -> Self {
+        LineUp {
+            team: String::new(),
+            players: Vec::new(),
+        }
+    }
+}
+impl Default for LineUp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A player on the tactical board.
+///
+/// Both positions are relative (`0.0..=1.0`): `x_pos` runs along the goal line, `y_pos` from
+/// the team's own goal towards the halfway line.
+#[derive(Debug, Serialize)]
+pub struct Player {
+    pub x_pos: f32,
+    pub y_pos: f32,
+    pub name: String,
+    /// Shirt number, zero-padded to two digits.
+    pub number: String,
+}
+
+/// A goal, card or substitution from the ticker of a match.
+#[derive(Debug, Clone, Serialize)]
+pub struct MatchEvent {
+    /// Minute of play; stoppage time counts as the last minute of the half, e.g. `90`.
+    pub minute: u32,
+    /// Minutes into stoppage time, e.g. `3` for `90+3`.
+    pub added: Option<u32>,
+    /// Short name of the team, as in [`LineUp::team`]. Own goals belong to the team they count
+    /// for.
+    pub team: String,
+    /// Whether `team` is the home side.
+    pub home: bool,
+    pub kind: MatchEventKind,
+    pub player: String,
+    /// Who set up a goal, or who was taken off for `player` in a substitution.
+    pub assist: Option<String>,
+}
+impl MatchEvent {
+    /// The minute as sportschau prints it, e.g. `67` or `90+3`.
+    pub fn minute_label(&self) -> String {
+        match self.added {
+            Some(added) => format!("{}+{added}", self.minute),
+            None => self.minute.to_string(),
+        }
+    }
+}
+
+/// What happened, serialized as e.g. `goal` or `yellow_red_card`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MatchEventKind {
+    Goal,
+    OwnGoal,
+    /// A goal from the penalty spot.
+    Penalty,
+    MissedPenalty,
+    YellowCard,
+    /// A second yellow card and with it a sending-off.
+    YellowRedCard,
+    RedCard,
+    Substitution,
+}
+impl MatchEventKind {
+    /// Whether the event changed the score.
+    pub fn is_goal(self) -> bool {
+        matches!(
+            self,
+            MatchEventKind::Goal | MatchEventKind::OwnGoal | MatchEventKind::Penalty
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn badges_of_games_in_play() {
+        assert_eq!(MatchStatus::from_sportschau("Live"), MatchStatus::Live);
+        assert_eq!(
+            MatchStatus::from_sportschau(" Halbzeit "),
+            MatchStatus::HalfTime
+        );
+        assert_eq!(
+            MatchStatus::from_sportschau("Verlängerung"),
+            MatchStatus::ExtraTime
+        );
+        assert_eq!(
+            MatchStatus::from_sportschau("Elfmeterschießen"),
+            MatchStatus::Penalties
+        );
+    }
+
+    #[test]
+    fn badges_of_games_not_in_play() {
+        assert_eq!(MatchStatus::from_sportschau("Beendet"), MatchStatus::Over);
+        assert_eq!(
+            MatchStatus::from_sportschau("Spiel abgesagt"),
+            MatchStatus::Postponed
+        );
+        assert_eq!(
+            MatchStatus::from_sportschau("verlegt"),
+            MatchStatus::Postponed
+        );
+        assert_eq!(MatchStatus::from_sportschau("18:30"), MatchStatus::Upcoming);
+        assert_eq!(MatchStatus::from_sportschau(""), MatchStatus::Upcoming);
+    }
+}
@@ -0,0 +1,378 @@
+//! Machine-readable output of scores, standings and lineups.
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::io::{self, Write};
+use std::str::FromStr;
+
+use crate::competition::Zone;
+use crate::events::{Event, EventKind};
+use crate::render::{self, Settings};
+use crate::schedule::{Dashboard, Fixture};
+use crate::standings::Column;
+use crate::{Competition, Game, LineUp, Locale, MatchEvent, SoccerError, Team};
+
+/// Version of the JSON documents written with [`Format::Json`]. Bumped whenever a field is
+/// renamed, removed or changes its type.
+pub const SCHEMA_VERSION: u32 = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    /// The box-drawn tables and the pitch.
+    #[default]
+    Table,
+    /// One JSON document wrapping the data together with [`SCHEMA_VERSION`].
+    Json,
+    /// One JSON object per game, team or player and line.
+    Ndjson,
+    /// A header line followed by one row per game, team or player.
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = SoccerError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(Format::Table),
+            "json" => Ok(Format::Json),
+            "ndjson" => Ok(Format::Ndjson),
+            "csv" => Ok(Format::Csv),
+            _ => Err(SoccerError::InvalidArgument(format!(
+                "unknown format '{s}', expected one of table, json, ndjson, csv"
+            ))),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Document<'a, T> {
+    schema_version: u32,
+    kind: &'a str,
+    data: T,
+}
+
+/// A player of a lineup flattened into one row, used for NDJSON and CSV.
+#[derive(Serialize)]
+struct PlayerRow<'a> {
+    side: &'a str,
+    team: &'a str,
+    number: &'a str,
+    name: &'a str,
+    x_pos: f32,
+    y_pos: f32,
+}
+
+/// A game together with its goals, cards and substitutions, used by `scores --detailed`.
+#[derive(Serialize)]
+struct DetailedGame<'a> {
+    #[serde(flatten)]
+    game: &'a Game,
+    events: &'a [MatchEvent],
+}
+
+/// The scores and the table of `soccer matchday` in one document.
+#[derive(Serialize)]
+struct Matchday<'a> {
+    scores: &'a [Game],
+    standings: &'a [Team],
+}
+
+/// A competition with its features flattened into one row, used for NDJSON and CSV.
+#[derive(Serialize)]
+struct CompetitionRow<'a> {
+    id: &'a str,
+    name: &'a str,
+    slug: &'a str,
+    standings: bool,
+    lineups: bool,
+}
+
+/// Prints the games of a matchday in the given format, tables with `settings`.
+pub fn emit_scores(
+    games: Vec<Game>,
+    format: Format,
+    settings: &Settings,
+) -> Result<(), SoccerError> {
+    if format == Format::Table {
+        render::print_scores(games, settings)?;
+        return Ok(());
+    }
+    emit("scores", &games, format)
+}
+
+/// Prints the games of a matchday with the `events` of each game in the given format: tables
+/// list the scorers under every game, JSON adds an `events` array to every game. CSV has no room
+/// for them and stays one plain row per game.
+pub fn emit_detailed_scores(
+    games: Vec<Game>,
+    events: Vec<Vec<MatchEvent>>,
+    format: Format,
+    settings: &Settings,
+) -> Result<(), SoccerError> {
+    match format {
+        Format::Table => {
+            render::print_detailed_scores(&games, &events, settings)?;
+            Ok(())
+        }
+        Format::Csv => emit("scores", &games, format),
+        Format::Json | Format::Ndjson => {
+            let rows: Vec<DetailedGame> = games
+                .iter()
+                .zip(&events)
+                .map(|(game, events)| DetailedGame { game, events })
+                .collect();
+            emit("scores", &rows, format)
+        }
+    }
+}
+
+/// Prints the league table in the given format, tables with `settings` and the `zones` of the
+/// competition coloured. Only tables are limited to `columns`, if any.
+pub fn emit_standings(
+    standings: Vec<Team>,
+    zones: &[Zone],
+    columns: &[Column],
+    format: Format,
+    settings: &Settings,
+) -> Result<(), SoccerError> {
+    if format == Format::Table {
+        render::print_standings(standings, zones, columns, settings)?;
+        return Ok(());
+    }
+    emit("standings", &standings, format)
+}
+
+/// Prints the games of a matchday followed by the league table. The structured formats wrap both
+/// in one document, so there is no CSV of it and NDJSON is the document on one line.
+pub fn emit_matchday(
+    games: Vec<Game>,
+    standings: Vec<Team>,
+    zones: &[Zone],
+    format: Format,
+    settings: &Settings,
+) -> Result<(), SoccerError> {
+    if format == Format::Table {
+        render::print_scores(games, settings)?;
+        render::print_standings(standings, zones, &[], settings)?;
+        return Ok(());
+    }
+    let matchday = Matchday {
+        scores: &games,
+        standings: &standings,
+    };
+    match format {
+        Format::Json => write_json("matchday", &matchday),
+        Format::Ndjson => emit("matchday", std::slice::from_ref(&matchday), format),
+        Format::Csv => Err(SoccerError::InvalidArgument(
+            "the matchday has no CSV format, use json or ndjson, or scores and standings"
+                .to_string(),
+        )),
+        Format::Table => unreachable!("printed above"),
+    }
+}
+
+/// Prints the fixtures of `club` in the given format, tables with `settings`.
+pub fn emit_fixtures(
+    club: &str,
+    fixtures: &[Fixture],
+    format: Format,
+    settings: &Settings,
+) -> Result<(), SoccerError> {
+    if format == Format::Table {
+        render::print_fixtures(club, fixtures, settings)?;
+        return Ok(());
+    }
+    emit("fixtures", fixtures, format)
+}
+
+/// Prints the dashboard of a club in the given format. It is no list, so there is no CSV of it
+/// and NDJSON is the document on one line.
+pub fn emit_dashboard(
+    dashboard: &Dashboard,
+    zones: &[Zone],
+    format: Format,
+    settings: &Settings,
+) -> Result<(), SoccerError> {
+    match format {
+        Format::Table => {
+            render::print_dashboard(dashboard, zones, settings)?;
+            Ok(())
+        }
+        Format::Json => write_json("team", dashboard),
+        Format::Ndjson => emit("team", std::slice::from_ref(dashboard), format),
+        Format::Csv => Err(SoccerError::InvalidArgument(
+            "the team dashboard has no CSV format, use json or ndjson".to_string(),
+        )),
+    }
+}
+
+/// Prints the goals, cards and substitutions of `game` in the given format. The table format
+/// draws the timeline.
+pub fn emit_events(
+    game: &Game,
+    events: &[MatchEvent],
+    format: Format,
+    settings: &Settings,
+) -> Result<(), SoccerError> {
+    if format == Format::Table {
+        render::print_events(game, events, settings)?;
+        return Ok(());
+    }
+    emit("events", events, format)
+}
+
+/// Prints both lineups of a match in the given format. The table format draws the pitch.
+pub fn emit_lineups(lineups: Vec<LineUp>, format: Format) -> Result<(), SoccerError> {
+    match format {
+        Format::Table => {
+            render::populate_stadium(lineups, render::construct_stadium())?;
+            Ok(())
+        }
+        Format::Json => write_json("lineups", &lineups),
+        Format::Ndjson | Format::Csv => {
+            let rows: Vec<PlayerRow> = lineups
+                .iter()
+                .zip(["home", "away"])
+                .flat_map(|(lineup, side)| {
+                    lineup.players.iter().map(move |player| PlayerRow {
+                        side,
+                        team: &lineup.team,
+                        number: &player.number,
+                        name: &player.name,
+                        x_pos: player.x_pos,
+                        y_pos: player.y_pos,
+                    })
+                })
+                .collect();
+            emit("lineups", &rows, format)
+        }
+    }
+}
+
+/// Prints the known competitions in the given format.
+pub fn emit_competitions(competitions: &[Competition], format: Format) -> Result<(), SoccerError> {
+    match format {
+        Format::Table => {
+            render::print_competitions(competitions)?;
+            Ok(())
+        }
+        Format::Json => write_json("competitions", competitions),
+        Format::Ndjson | Format::Csv => {
+            let rows: Vec<CompetitionRow> = competitions
+                .iter()
+                .map(|competition| CompetitionRow {
+                    id: competition.id,
+                    name: competition.name,
+                    slug: competition.slug,
+                    standings: competition.features.standings,
+                    lineups: competition.features.lineups,
+                })
+                .collect();
+            emit("competitions", &rows, format)
+        }
+    }
+}
+
+fn emit<T: Serialize>(kind: &str, items: &[T], format: Format) -> Result<(), SoccerError> {
+    match format {
+        Format::Json => write_json(kind, items),
+        Format::Ndjson => {
+            let mut out = io::stdout().lock();
+            for item in items {
+                serde_json::to_writer(&mut out, item)?;
+                writeln!(out)?;
+            }
+            Ok(())
+        }
+        Format::Csv => {
+            let mut writer = csv::Writer::from_writer(io::stdout().lock());
+            for item in items {
+                writer.serialize(item)?;
+            }
+            writer.flush()?;
+            Ok(())
+        }
+        Format::Table => unreachable!("tables are rendered by the callers"),
+    }
+}
+
+fn write_json<T: Serialize + ?Sized>(kind: &str, data: &T) -> Result<(), SoccerError> {
+    let document = Document {
+        schema_version: SCHEMA_VERSION,
+        kind,
+        data,
+    };
+    let mut out = io::stdout().lock();
+    serde_json::to_writer_pretty(&mut out, &document)?;
+    writeln!(out)?;
+    Ok(())
+}
+
+/// An event flattened into one row, used for CSV.
+#[derive(Serialize)]
+struct EventRow<'a> {
+    event: &'a str,
+    home: &'a str,
+    away: &'a str,
+    score_home: usize,
+    score_away: usize,
+    team: Option<&'a str>,
+    kickoff: Option<String>,
+}
+
+/// Prints events one line at a time, as they arrive.
+pub struct EventWriter {
+    format: Format,
+    locale: Locale,
+    csv: Option<csv::Writer<io::Stdout>>,
+}
+
+impl EventWriter {
+    /// Table lines are stamped with the time in the zone of `locale`.
+    pub fn new(format: Format, locale: &Locale) -> Self {
+        let csv = (format == Format::Csv).then(|| csv::Writer::from_writer(io::stdout()));
+        EventWriter {
+            format,
+            locale: *locale,
+            csv,
+        }
+    }
+
+    pub fn write(&mut self, event: &Event) -> Result<(), SoccerError> {
+        match (self.format, self.csv.as_mut()) {
+            (Format::Table, _) => writeln!(
+                io::stdout().lock(),
+                "{} {}",
+                Utc::now().with_timezone(&self.locale.tz).format("%H:%M:%S"),
+                event.describe(&self.locale)
+            )?,
+            (Format::Json | Format::Ndjson, _) => {
+                writeln!(io::stdout().lock(), "{}", serde_json::to_string(event)?)?
+            }
+            (Format::Csv, Some(writer)) => {
+                let (event_name, team, kickoff) = match &event.kind {
+                    EventKind::KickOff => ("kick_off", None, None),
+                    EventKind::Goal { team } => ("goal", Some(team.as_str()), None),
+                    EventKind::ScoreCorrected => ("score_corrected", None, None),
+                    EventKind::HalfTime => ("half_time", None, None),
+                    EventKind::FullTime => ("full_time", None, None),
+                    EventKind::Postponed { kickoff } => {
+                        ("postponed", None, Some(kickoff.to_rfc3339()))
+                    }
+                };
+                writer.serialize(EventRow {
+                    event: event_name,
+                    home: &event.home,
+                    away: &event.away,
+                    score_home: event.score_home,
+                    score_away: event.score_away,
+                    team,
+                    kickoff,
+                })?;
+                writer.flush()?;
+            }
+            (Format::Csv, None) => unreachable!("the CSV writer is created with the EventWriter"),
+        }
+        Ok(())
+    }
+}
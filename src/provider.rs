@@ -0,0 +1,663 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use chrono_tz::Europe::Berlin;
+use reqwest::header::{self, HeaderValue};
+use reqwest::StatusCode;
+use scraper::{selectable::Selectable, ElementRef, Html, Selector};
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::cache::{Cache, CacheTtls, Entry};
+use crate::{
+    fuzzy, Game, LineUp, MatchEvent, MatchEventKind, MatchStatus, Player, ShortNames, SoccerError,
+    Team,
+};
+
+const BASE_URL: &str = "https://www.sportschau.de";
+const TIMEOUT: Duration = Duration::from_secs(15);
+/// Pages requested at the same time by commands that need one per matchday.
+pub const CONCURRENT_PAGES: usize = 4;
+
+/// A source for scores, standings, lineups and match events.
+///
+/// `link` is always a sportschau URL: the games page of a competition for `scores`, its table page
+/// for `standings` (see [`Competition`](crate::Competition)) or the match link found in
+/// `Game.link` for `lineup` and `events`, so that every provider resolves the same resources.
+#[async_trait]
+pub trait DataProvider: Send + Sync {
+    async fn scores(&self, link: &str) -> Result<Vec<Game>, SoccerError>;
+    async fn standings(&self, link: &str) -> Result<Vec<Team>, SoccerError>;
+    async fn lineup(&self, link: &str) -> Result<Vec<LineUp>, SoccerError>;
+    async fn events(&self, link: &str) -> Result<Vec<MatchEvent>, SoccerError>;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Scrapes the live pages on sportschau.de, optionally through a [`Cache`].
+pub struct Sportschau {
+    client: reqwest::Client,
+    cache: Option<Cache>,
+    ttls: CacheTtls,
+    offline: bool,
+}
+
+/// A fetched page and the validators needed to revalidate it later.
+struct Page {
+    body: String,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    /// The page was downloaded or revalidated, so the cache needs updating.
+    refreshed: bool,
+}
+
+impl Sportschau {
+    pub fn new() -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(TIMEOUT)
+            .build()
+            .unwrap_or_default();
+        Sportschau {
+            client,
+            cache: None,
+            ttls: CacheTtls::default(),
+            offline: false,
+        }
+    }
+    /// Serves pages from `cache` while they are younger than their TTL and stores every
+    /// downloaded page in it.
+    pub fn with_cache(mut self, cache: Cache, ttls: CacheTtls) -> Self {
+        self.cache = Some(cache);
+        self.ttls = ttls;
+        self
+    }
+    /// Never touches the network: every page is served from the cache, however old it is.
+    pub fn offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    async fn fetch(&self, url: &str) -> Result<Page, SoccerError> {
+        let cached = self.cache.as_ref().and_then(|cache| cache.get(url));
+        if let Some(entry) = cached.as_ref().filter(|e| self.offline || e.is_fresh()) {
+            return Ok(Page {
+                body: entry.body.clone(),
+                etag: None,
+                last_modified: None,
+                refreshed: false,
+            });
+        }
+        if self.offline {
+            return Err(SoccerError::NotCached(url.to_string()));
+        }
+
+        let mut request = self.client.get(url);
+        if let Some(entry) = &cached {
+            if let Some(etag) = &entry.etag {
+                request = request.header(header::IF_NONE_MATCH, etag);
+            }
+            if let Some(last_modified) = &entry.last_modified {
+                request = request.header(header::IF_MODIFIED_SINCE, last_modified);
+            }
+        }
+        let response = request.send().await?.error_for_status()?;
+        if let Some(entry) = cached.filter(|_| response.status() == StatusCode::NOT_MODIFIED) {
+            return Ok(Page {
+                body: entry.body,
+                etag: entry.etag,
+                last_modified: entry.last_modified,
+                refreshed: true,
+            });
+        }
+        let header_value = |name| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|value: &HeaderValue| value.to_str().ok())
+                .map(str::to_string)
+        };
+        let etag = header_value(header::ETAG);
+        let last_modified = header_value(header::LAST_MODIFIED);
+        Ok(Page {
+            body: response.text().await?,
+            etag,
+            last_modified,
+            refreshed: true,
+        })
+    }
+
+    /// Remembers a page that parsed successfully. The cache is best effort: if it cannot be
+    /// written, the command still succeeds.
+    fn store(&self, url: &str, page: Page, ttl: Duration) {
+        let Some(cache) = self.cache.as_ref().filter(|_| page.refreshed) else {
+            return;
+        };
+        let _ = cache.put(&Entry {
+            url: url.to_string(),
+            fetched_at: Utc::now(),
+            ttl,
+            etag: page.etag,
+            last_modified: page.last_modified,
+            body: page.body,
+        });
+    }
+}
+impl Default for Sportschau {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl DataProvider for Sportschau {
+    async fn scores(&self, link: &str) -> Result<Vec<Game>, SoccerError> {
+        let page = self.fetch(link).await?;
+        let games = parse_scores(&page.body)?;
+        self.store(link, page, self.ttls.for_scores(&games));
+        Ok(games)
+    }
+    async fn standings(&self, link: &str) -> Result<Vec<Team>, SoccerError> {
+        let page = self.fetch(link).await?;
+        let teams = parse_standings(&page.body)?;
+        self.store(link, page, self.ttls.standings);
+        Ok(teams)
+    }
+    async fn lineup(&self, link: &str) -> Result<Vec<LineUp>, SoccerError> {
+        let url = lineup_url(link);
+        let page = self.fetch(&url).await?;
+        let lineups = parse_lineup(&page.body)?;
+        let ttl = match parse_match_status(&page.body)? {
+            Some(status) if status.is_final() => self.ttls.finished,
+            _ => self.ttls.lineup,
+        };
+        self.store(&url, page, ttl);
+        Ok(lineups)
+    }
+    async fn events(&self, link: &str) -> Result<Vec<MatchEvent>, SoccerError> {
+        let url = events_url(link);
+        let page = self.fetch(&url).await?;
+        let events = parse_events(&page.body)?;
+        // The ticker of a finished game does not change any more.
+        let ttl = match parse_match_status(&page.body)? {
+            Some(status) if status.is_final() => self.ttls.finished,
+            _ => self.ttls.live,
+        };
+        self.store(&url, page, ttl);
+        Ok(events)
+    }
+}
+
+/// Reads previously saved sportschau pages from a directory instead of the network.
+///
+/// The directory mirrors the URL paths, e.g. the standings of
+/// `https://www.sportschau.de/live-und-ergebnisse/fussball/deutschland-bundesliga/tabelle` are
+/// read from `<dir>/live-und-ergebnisse/fussball/deutschland-bundesliga/tabelle.html`.
+pub struct Fixtures {
+    dir: PathBuf,
+}
+
+impl Fixtures {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Fixtures { dir: dir.into() }
+    }
+    fn path_for(&self, url: &str) -> PathBuf {
+        let path = url.strip_prefix(BASE_URL).unwrap_or(url).trim_matches('/');
+        self.dir.join(format!("{path}.html"))
+    }
+    async fn fetch(&self, url: &str) -> Result<String, SoccerError> {
+        let path = self.path_for(url);
+        tokio::fs::read_to_string(&path).await.map_err(|e| {
+            SoccerError::Io(io::Error::new(
+                e.kind(),
+                format!("could not read fixture {}: {e}", path.display()),
+            ))
+        })
+    }
+}
+
+#[async_trait]
+impl DataProvider for Fixtures {
+    async fn scores(&self, link: &str) -> Result<Vec<Game>, SoccerError> {
+        let body = self.fetch(link).await?;
+        parse_scores(&body)
+    }
+    async fn standings(&self, link: &str) -> Result<Vec<Team>, SoccerError> {
+        let body = self.fetch(link).await?;
+        parse_standings(&body)
+    }
+    async fn lineup(&self, link: &str) -> Result<Vec<LineUp>, SoccerError> {
+        let body = self.fetch(&lineup_url(link)).await?;
+        parse_lineup(&body)
+    }
+    async fn events(&self, link: &str) -> Result<Vec<MatchEvent>, SoccerError> {
+        let body = self.fetch(&events_url(link)).await?;
+        parse_events(&body)
+    }
+}
+
+/// Finds the game best matching `query_string` among the current games and returns its link.
+pub async fn get_lineup_link(
+    provider: &dyn DataProvider,
+    query_string: String,
+    comp_link: &str,
+) -> Result<String, SoccerError> {
+    let candidates = find_games(provider, &query_string, comp_link).await?;
+    match_link(&candidates[0].1)
+}
+
+/// The current games matching `query`, best match first with their scores. Never empty: if no
+/// game matches, the error names the teams closest to the query.
+pub async fn find_games(
+    provider: &dyn DataProvider,
+    query: &str,
+    comp_link: &str,
+) -> Result<Vec<(u32, Game)>, SoccerError> {
+    let games = provider.scores(comp_link).await?;
+    let teams: Vec<String> = games
+        .iter()
+        .flat_map(|game| [game.home.clone(), game.away.clone()])
+        .collect();
+    let candidates = fuzzy::rank(query, games);
+    if candidates.is_empty() {
+        return Err(SoccerError::NoMatch {
+            query: query.to_string(),
+            closest: fuzzy::closest(query, &teams, 3),
+        });
+    }
+    Ok(candidates)
+}
+
+/// The link of the match page of `game`, which sportschau only adds some time before kickoff.
+pub fn match_link(game: &Game) -> Result<String, SoccerError> {
+    if game.link.is_empty() {
+        return Err(SoccerError::Markup(format!(
+            "no match page for {} - {}",
+            game.home, game.away
+        )));
+    }
+    Ok(game.link.clone())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn lineup_url(link: &str) -> String {
+    construct_url(BASE_URL, link, "/taktische-aufstellung")
+}
+fn events_url(link: &str) -> String {
+    construct_url(BASE_URL, link, "/spielinfos")
+}
+fn selector(css: &str) -> Result<Selector, SoccerError> {
+    Selector::parse(css).map_err(|e| SoccerError::Markup(format!("invalid selector {css}: {e}")))
+}
+fn construct_url(base: &str, link: &str, segment: &str) -> String {
+    match link.rsplit_once('/') {
+        Some((base_url, _)) => format!("{base}{base_url}{segment}"),
+        None => link.to_string(),
+    }
+}
+
+// Score Stuff
+/// Parses the games of a sportschau "Spiele und Ergebnisse" page.
+pub fn parse_scores(body: &str) -> Result<Vec<Game>, SoccerError> {
+    let document = Html::parse_document(body);
+    let mut games: Vec<Game> = Vec::<Game>::new();
+
+    let sel_match = selector("li.match")?;
+    let sel_teams = selector("div.team-name")?;
+    let sel_home = selector("div.match-home")?;
+    let sel_away = selector("div.match-away")?;
+    let sel_status = selector("div.match-status")?;
+    let sel_minute = selector("div.current-minute")?;
+    let sel_score_home = selector("div.match-result-home")?;
+    let sel_score_away = selector("div.match-result-away")?;
+    let sel_link = selector("div.match-more")?;
+    let sel_a = selector("a")?;
+    // The heading reads e.g. `7. Spieltag`.
+    let matchday = document
+        .select(&selector("div.module-matches h2")?)
+        .next()
+        .and_then(|heading| {
+            let heading = heading.text().collect::<String>();
+            heading.trim().split_once(". Spieltag")?.0.parse().ok()
+        });
+    for element in document.select(&sel_match) {
+        let mut teams = element.select(&sel_teams).map(|team| team.inner_html());
+        let (Some(home), Some(away)) = (teams.next(), teams.next()) else {
+            return Err(SoccerError::Markup("match without two team names".into()));
+        };
+
+        let home_names = short_names(element, &sel_home)?;
+        let away_names = short_names(element, &sel_away)?;
+
+        let kickoff_known = element.value().attr("data-datetime").is_some();
+        let timestamp = match element.value().attr("data-datetime") {
+            Some(ts) => ts
+                .parse::<DateTime<Utc>>()
+                .map_err(|_| SoccerError::Markup(format!("invalid kickoff time '{ts}'")))?
+                .with_timezone(&Berlin),
+            None => Utc::now().with_timezone(&Berlin),
+        };
+
+        let score_home: usize = parse_score(element, &sel_score_home);
+        let score_away: usize = parse_score(element, &sel_score_away);
+
+        let status = element
+            .select(&sel_status)
+            .next()
+            .map(|status| MatchStatus::from_sportschau(&status.inner_html()))
+            .ok_or_else(|| SoccerError::Markup(format!("no status for {home} - {away}")))?;
+        let minute = element
+            .select(&sel_minute)
+            .next()
+            .map(|minute| {
+                minute
+                    .inner_html()
+                    .trim()
+                    .trim_end_matches('\'')
+                    .to_string()
+            })
+            .filter(|minute| status.is_live() && !minute.is_empty());
+
+        let mut link: String = String::new();
+        if let Some(href) = element
+            .select(&sel_link)
+            .next()
+            .and_then(|el| el.select(&sel_a).next())
+            .and_then(|a| a.value().attr("href"))
+        {
+            link = href.to_string();
+        }
+
+        games.push(Game {
+            home,
+            away,
+            score_home,
+            score_away,
+            timestamp,
+            kickoff_known,
+            status,
+            minute,
+            link,
+            matchday,
+            home_names,
+            away_names,
+        })
+    }
+    if games.is_empty() {
+        return Err(SoccerError::Markup("no games found".into()));
+    }
+    Ok(games)
+}
+/// The short name and abbreviation of the side of `element` matched by `side`, empty if missing.
+fn short_names(element: ElementRef, side: &Selector) -> Result<ShortNames, SoccerError> {
+    let Some(side) = element.select(side).next() else {
+        return Ok(ShortNames::default());
+    };
+    let text = |css: &str| -> Result<String, SoccerError> {
+        Ok(side
+            .select(&selector(css)?)
+            .next()
+            .map(|cell| cell.text().collect::<String>().trim().to_string())
+            .unwrap_or_default())
+    };
+    Ok(ShortNames {
+        short: text(".team-shortname")?,
+        abbrev: text(".team-abbreviation")?,
+    })
+}
+fn parse_score(element: ElementRef, selector: &Selector) -> usize {
+    element
+        .select(selector)
+        .next()
+        .and_then(|score_element| score_element.first_child())
+        .and_then(ElementRef::wrap)
+        .map(|child_element| child_element.inner_html())
+        .unwrap_or_default()
+        .parse::<usize>()
+        .unwrap_or(0)
+}
+
+// Standing Stuff
+/// Parses the rows of a sportschau "Tabelle" page.
+pub fn parse_standings(body: &str) -> Result<Vec<Team>, SoccerError> {
+    let document = Html::parse_document(body);
+    let sel_tr = selector("tr[class^='hs_team_id-']")?;
+    let mut teams: Vec<Team> = Vec::<Team>::new();
+    let mut skipped = None;
+    // A single broken row should not cost the whole table, so it is reported and left out.
+    for (index, item) in document.select(&sel_tr).enumerate() {
+        match parse_team(item) {
+            Ok(team) => teams.push(team),
+            Err(e) => {
+                eprintln!("warning: skipping row {} of the table: {e}", index + 1);
+                skipped.get_or_insert(e);
+            }
+        }
+    }
+    if teams.is_empty() {
+        return Err(skipped.unwrap_or_else(|| SoccerError::Markup("no table rows found".into())));
+    }
+    Ok(teams)
+}
+
+/// Parses one row of the table.
+fn parse_team(item: ElementRef) -> Result<Team, SoccerError> {
+    let id = item
+        .value()
+        .classes()
+        .find_map(|class| class.strip_prefix("hs_team_id-"))
+        .and_then(|id| id.parse().ok())
+        .ok_or_else(|| SoccerError::Markup("table row without a team id".into()))?;
+    let text = |css: &str| -> Result<String, SoccerError> {
+        let cell = item
+            .select(&selector(css)?)
+            .next()
+            .ok_or_else(|| SoccerError::Markup(format!("table row without {css}")))?;
+        Ok(cell.text().collect::<String>().trim().to_string())
+    };
+    let name = text(".team-name")?;
+    let number = |css: &str| -> Result<i32, SoccerError> {
+        let value = text(css)?;
+        // The position may carry a dot and negative differences a real minus sign.
+        value
+            .trim_end_matches('.')
+            .replace('\u{2212}', "-")
+            .parse()
+            .map_err(|_| SoccerError::Markup(format!("invalid {css} '{value}' for {name}")))
+    };
+    let count = |css: &str| -> Result<u32, SoccerError> {
+        let value = number(css)?;
+        u32::try_from(value)
+            .map_err(|_| SoccerError::Markup(format!("negative {css} '{value}' for {name}")))
+    };
+    let goals = text(".goals")?;
+    let (goals_for, goals_against) = goals
+        .split_once(':')
+        .and_then(|(scored, conceded)| {
+            Some((scored.trim().parse().ok()?, conceded.trim().parse().ok()?))
+        })
+        .ok_or_else(|| SoccerError::Markup(format!("invalid goals '{goals}' for {name}")))?;
+    Ok(Team {
+        id,
+        standing: count(".standing")?,
+        short: text(".team-shortname")?,
+        abbrev: text(".team-abbreviation")?,
+        games: count(".games")?,
+        wins: count(".wins")?,
+        draws: count(".draws")?,
+        losses: count(".losses")?,
+        goals_for,
+        goals_against,
+        goal_dif: number(".goal-difference")?,
+        points: count(".points")?,
+        change: None,
+        name,
+    })
+}
+
+// Line-Up Stuff
+/// Parses the home and away starting elevens of a sportschau "Taktische Aufstellung" page.
+pub fn parse_lineup(body: &str) -> Result<Vec<LineUp>, SoccerError> {
+    let document = Html::parse_document(body);
+    let mut home_lineup: LineUp = LineUp::new();
+    let mut away_lineup: LineUp = LineUp::new();
+
+    let sel_home = selector("div[class^='hs-starter home']")?;
+    let sel_away = selector("div[class^='hs-starter away']")?;
+    let sel_player = selector("div[class^='tactic'")?;
+    let sel_home_name = selector("div.team-shortname-home")?;
+    let sel_away_name = selector("div.team-shortname-away")?;
+
+    if let Some(teamname) = document.select(&sel_home_name).next() {
+        home_lineup.team = teamname.text().collect();
+    }
+    if let Some(teamname) = document.select(&sel_away_name).next() {
+        away_lineup.team = teamname.text().collect();
+    }
+
+    let hl_div = document
+        .select(&sel_home)
+        .next()
+        .ok_or_else(|| SoccerError::Markup("no home lineup found".into()))?;
+    for element in hl_div.select(&sel_player) {
+        home_lineup.players.push(parse_player(element, false)?);
+    }
+    let al_div = document
+        .select(&sel_away)
+        .next()
+        .ok_or_else(|| SoccerError::Markup("no away lineup found".into()))?;
+    for element in al_div.select(&sel_player) {
+        away_lineup.players.push(parse_player(element, true)?);
+    }
+    for lineup in [&mut home_lineup, &mut away_lineup] {
+        lineup.players.sort_by(|p, p2| {
+            p.x_pos
+                .partial_cmp(&p2.x_pos)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        lineup.players.sort_by(|p, p2| {
+            p.y_pos
+                .partial_cmp(&p2.y_pos)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+    }
+
+    Ok(vec![home_lineup, away_lineup])
+}
+fn parse_player(element: ElementRef, mirrored: bool) -> Result<Player, SoccerError> {
+    let mut x_pos: f32 = element
+        .attr("data-xpos")
+        .and_then(|e| e.parse::<f32>().ok())
+        .unwrap_or(0.0);
+    let y_pos: f32 = element
+        .attr("data-ypos")
+        .and_then(|e| e.parse::<f32>().ok())
+        .unwrap_or(0.0);
+    if mirrored {
+        x_pos = 1.0 - x_pos;
+    }
+    let text_content: Vec<_> = element.text().collect();
+    let [number, name, ..] = text_content.as_slice() else {
+        return Err(SoccerError::Markup("player without number and name".into()));
+    };
+    let number_u8: u8 = number.parse::<u8>().unwrap_or(0);
+    let number: String = format!("{:02}", number_u8);
+    let name: String = name.to_string();
+    Ok(Player {
+        x_pos,
+        y_pos,
+        name,
+        number,
+    })
+}
+
+// Match Event Stuff
+/// Parses the goals, cards and substitutions in the ticker of a sportschau "Spielinfos" page,
+/// ordered by minute. Ticker entries of other kinds, e.g. the kickoff, are left out.
+pub fn parse_events(body: &str) -> Result<Vec<MatchEvent>, SoccerError> {
+    let document = Html::parse_document(body);
+    let sel_ticker = selector("ul.hs-ticker")?;
+    let sel_event = selector("li.hs-event")?;
+    let sel_player = selector("span.player")?;
+    let sel_assist = selector("span.related-player")?;
+    let sel_home_name = selector("div.team-shortname-home")?;
+    let sel_away_name = selector("div.team-shortname-away")?;
+
+    let team_name = |sel: &Selector| -> String {
+        document
+            .select(sel)
+            .next()
+            .map(|name| name.text().collect::<String>().trim().to_string())
+            .unwrap_or_default()
+    };
+    let (home_name, away_name) = (team_name(&sel_home_name), team_name(&sel_away_name));
+    let ticker = document
+        .select(&sel_ticker)
+        .next()
+        .ok_or_else(|| SoccerError::Markup("no ticker found".into()))?;
+
+    let mut events = Vec::new();
+    for element in ticker.select(&sel_event) {
+        let classes: Vec<&str> = element.value().classes().collect();
+        let Some(kind) = classes.iter().find_map(|class| event_kind(class)) else {
+            continue;
+        };
+        let home = classes.contains(&"home");
+        if !home && !classes.contains(&"away") {
+            return Err(SoccerError::Markup("ticker event without a side".into()));
+        }
+        let minute = element.attr("data-minute").unwrap_or_default();
+        let (minute, added) = match minute.split_once('+') {
+            Some((minute, added)) => (minute, Some(added)),
+            None => (minute, None),
+        };
+        let number = |text: &str| {
+            text.trim()
+                .parse::<u32>()
+                .map_err(|_| SoccerError::Markup(format!("invalid minute '{text}' in the ticker")))
+        };
+        let text = |sel: &Selector| {
+            element
+                .select(sel)
+                .next()
+                .map(|span| span.text().collect::<String>().trim().to_string())
+        };
+        events.push(MatchEvent {
+            minute: number(minute)?,
+            added: added.map(number).transpose()?,
+            team: if home { &home_name } else { &away_name }.clone(),
+            home,
+            kind,
+            player: text(&sel_player).unwrap_or_default(),
+            assist: text(&sel_assist).filter(|name| !name.is_empty()),
+        });
+    }
+    // The ticker lists the latest event first, so events of the same minute are turned around
+    // before the (stable) sort.
+    events.reverse();
+    events.sort_by_key(|event| (event.minute, event.added));
+    Ok(events)
+}
+/// The status in the header of a match page, e.g. "Spielinfos" or "Taktische Aufstellung",
+/// `None` if the page shows none.
+pub fn parse_match_status(body: &str) -> Result<Option<MatchStatus>, SoccerError> {
+    let document = Html::parse_document(body);
+    let sel_status = selector("div.hs-match-header div.match-status")?;
+    Ok(document
+        .select(&sel_status)
+        .next()
+        .map(|status| MatchStatus::from_sportschau(&status.text().collect::<String>())))
+}
+fn event_kind(class: &str) -> Option<MatchEventKind> {
+    match class {
+        "goal" => Some(MatchEventKind::Goal),
+        "own-goal" => Some(MatchEventKind::OwnGoal),
+        "penalty" => Some(MatchEventKind::Penalty),
+        "penalty-missed" => Some(MatchEventKind::MissedPenalty),
+        "yellow-card" => Some(MatchEventKind::YellowCard),
+        "yellow-red-card" => Some(MatchEventKind::YellowRedCard),
+        "red-card" => Some(MatchEventKind::RedCard),
+        "substitution" => Some(MatchEventKind::Substitution),
+        _ => None,
+    }
+}
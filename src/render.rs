@@ -0,0 +1,963 @@
+use chrono::{DateTime, Datelike, Utc};
+use chrono_tz::Tz;
+use colored::{ColoredString, Colorize};
+use prettytable::{cell, format, row, Row, Table};
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::Duration;
+
+use crate::cache::CacheStats;
+use crate::competition::{self, Zone, ZoneKind};
+use crate::fuzzy::Favourites;
+use crate::locale::Locale;
+use crate::schedule::{Dashboard, Fixture, Outcome, Venue};
+use crate::standings::Column;
+use crate::theme::{Theme, THEMES};
+use crate::{Competition, Game, LineUp, MatchEvent, MatchEventKind, MatchStatus, ShortNames, Team};
+
+/// How the tables are printed.
+pub struct Settings {
+    pub locale: Locale,
+    /// Names of these teams are coloured, their table rows printed in bold.
+    pub favourites: Favourites,
+    pub theme: &'static Theme,
+    pub layout: Layout,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            locale: Locale::default(),
+            favourites: Favourites::default(),
+            theme: &THEMES[0],
+            layout: Layout::default(),
+        }
+    }
+}
+
+/// How much room the tables may take.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Layout {
+    /// Full team names and every column.
+    #[default]
+    Wide,
+    /// Abbreviated team names and only the essential columns.
+    Compact,
+    /// The most detailed table that is at most this many characters wide.
+    Fit(usize),
+}
+
+impl Layout {
+    /// Builds the table for the first of `variants`, ordered from the most detailed to the most
+    /// compact, that suits this layout.
+    fn pick<V: Copy>(self, variants: &[V], build: impl Fn(V) -> Table) -> Table {
+        let width = match self {
+            Layout::Wide => return build(variants[0]),
+            Layout::Compact => return build(variants[variants.len() - 1]),
+            Layout::Fit(width) => width,
+        };
+        let mut table = Table::new();
+        for &variant in variants {
+            table = build(variant);
+            if table_width(&table) <= width {
+                break;
+            }
+        }
+        table
+    }
+}
+
+/// Width of the top border of `table`, which is as wide as the whole table.
+fn table_width(table: &Table) -> usize {
+    table
+        .to_string()
+        .lines()
+        .next()
+        .map_or(0, |line| line.chars().count())
+}
+
+/// Which of its names a team is printed with.
+#[derive(Clone, Copy)]
+enum Names {
+    Long,
+    Short,
+    Abbrev,
+}
+
+/// Parts of a club's name left out of its short name, e.g. the `FC` of `FC Augsburg`. Numbers,
+/// like the `04` of `Bayer 04 Leverkusen`, are left out as well.
+const CLUB_AFFIXES: &[&str] = &[
+    "1.", "FC", "SV", "SC", "BV", "RB", "VfB", "VfL", "TSG", "FSV", "SpVgg", "Bayer", "Borussia",
+];
+
+/// The name of a club without its affixes, e.g. `Union Berlin` for `1. FC Union Berlin`.
+pub fn short_name(name: &str) -> String {
+    let words: Vec<&str> = name
+        .split_whitespace()
+        .filter(|word| !CLUB_AFFIXES.contains(word))
+        .filter(|word| !word.chars().all(|c| c.is_ascii_digit() || c == '.'))
+        .collect();
+    if words.is_empty() {
+        name.to_string()
+    } else {
+        words.join(" ")
+    }
+}
+
+/// Three capitals standing for a club, taken from its short name, e.g. `STP` for `FC St. Pauli`.
+pub fn abbreviation(name: &str) -> String {
+    short_name(name)
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .take(3)
+        .collect::<String>()
+        .to_uppercase()
+}
+
+/// The name of a club as `names` asks for: sportschau's own short name or abbreviation from
+/// `given`, made up from the full name only where the page has none.
+fn team_name(name: &str, given: &ShortNames, names: Names) -> String {
+    match names {
+        Names::Long => name.to_string(),
+        Names::Short if !given.short.is_empty() => given.short.clone(),
+        Names::Abbrev if !given.abbrev.is_empty() => given.abbrev.clone(),
+        Names::Short => short_name(name),
+        Names::Abbrev => abbreviation(name),
+    }
+}
+
+/// `text` in bold if `stressed`.
+fn bold_if(text: String, stressed: bool) -> String {
+    if stressed {
+        text.bold().to_string()
+    } else {
+        text
+    }
+}
+
+// Score Stuff
+/// Prints the games of a matchday as a table.
+pub fn print_scores(info: Vec<Game>, settings: &Settings) -> io::Result<()> {
+    let table = scores_table(&info, Utc::now(), settings, |_| false);
+    table.print(&mut io::stdout().lock())?;
+    Ok(())
+}
+/// Prints the games of a matchday as a table with the scorers under every game, `events` holding
+/// those of each game in the same order.
+pub fn print_detailed_scores(
+    info: &[Game],
+    events: &[Vec<MatchEvent>],
+    settings: &Settings,
+) -> io::Result<()> {
+    let now = Utc::now();
+    let table = settings
+        .layout
+        .pick(&[Names::Long, Names::Short, Names::Abbrev], |names| {
+            scores_table_with(info, events, now, settings, &|_| false, names)
+        });
+    table.print(&mut io::stdout().lock())?;
+    Ok(())
+}
+/// Builds the scores table as of `now`, with the score of every game for which `highlight`
+/// returns `true` stressed. The team names are shortened as far as the layout asks for.
+pub fn scores_table(
+    info: &[Game],
+    now: DateTime<Utc>,
+    settings: &Settings,
+    highlight: impl Fn(&Game) -> bool,
+) -> Table {
+    settings
+        .layout
+        .pick(&[Names::Long, Names::Short, Names::Abbrev], |names| {
+            scores_table_with(info, &[], now, settings, &highlight, names)
+        })
+}
+fn scores_table_with(
+    info: &[Game],
+    events: &[Vec<MatchEvent>],
+    now: DateTime<Utc>,
+    settings: &Settings,
+    highlight: &impl Fn(&Game) -> bool,
+    names: Names,
+) -> Table {
+    let locale = &settings.locale;
+    let mut table = Table::new();
+    table.set_format(*format::consts::FORMAT_BOX_CHARS);
+    table.add_row(Row::from(locale.words().scores_header));
+    let theme = settings.theme;
+    for (i, item) in info.iter().enumerate() {
+        let started = item.status.is_live() || item.status == MatchStatus::Over;
+        let team = |name: &str, given: &ShortNames, won: bool| {
+            let mut styled = team_name(name, given, names).normal();
+            if settings.favourites.contains(name) {
+                styled = styled.color(theme.favourite);
+            }
+            if won && started {
+                styled = styled.bold();
+            }
+            styled.to_string()
+        };
+        let mut home = team(
+            &item.home,
+            &item.home_names,
+            item.score_home > item.score_away,
+        );
+        let mut away = team(
+            &item.away,
+            &item.away_names,
+            item.score_away > item.score_home,
+        );
+        // The scorers go under the team names, in the same row.
+        if let Some(events) = events.get(i) {
+            for (name, side) in [(&mut home, true), (&mut away, false)] {
+                for line in scorers(events, side) {
+                    name.push_str(&format!("\n{}", line.dimmed()));
+                }
+            }
+        }
+
+        let mut score = format!("{} - {}", item.score_home, item.score_away).normal();
+        let mut date = time_label(item, now, locale).normal();
+        if item.status.is_live() {
+            score = score.color(theme.live);
+            date = date.color(theme.live);
+        } else if item.status.is_final() {
+            score = score.dimmed();
+            date = date.dimmed();
+        }
+        if highlight(item) {
+            score = score.clear().bold().color(theme.changed);
+        }
+        table.add_row(row![cell!(home), cell!(score), cell!(away), cell!(date)]);
+    }
+    table
+}
+/// What the time column shows for `game`: its kickoff until it starts, then its status.
+pub fn time_label(game: &Game, now: DateTime<Utc>, locale: &Locale) -> String {
+    match game.status {
+        MatchStatus::Upcoming => kickoff_label(game.timestamp, now, locale),
+        _ => status_label(game),
+    }
+}
+/// Games kicking off within this span get a countdown instead of a time.
+pub const COUNTDOWN: Duration = Duration::from_secs(3 * 60 * 60);
+/// When `kickoff` is, seen from `now`: a countdown like `in 2h 15m` shortly before, otherwise
+/// `Today, 18:30`, `Tomorrow, 15:30`, the weekday within a week or the full date. Days are
+/// counted in the zone of `locale`.
+pub fn kickoff_label(kickoff: DateTime<Tz>, now: DateTime<Utc>, locale: &Locale) -> String {
+    let words = locale.words();
+    if let Ok(until) = (kickoff.with_timezone(&Utc) - now).to_std() {
+        if until < COUNTDOWN {
+            return countdown(until, locale);
+        }
+    }
+    let today = now.with_timezone(&locale.tz).date_naive();
+    let day = kickoff.with_timezone(&locale.tz);
+    let time = locale.time(&kickoff);
+    match (day.date_naive() - today).num_days() {
+        -1 => format!("{}, {time}", words.yesterday),
+        0 => format!("{}, {time}", words.today),
+        1 => format!("{}, {time}", words.tomorrow),
+        2..=6 => format!("{}, {time}", locale.weekday(day.weekday())),
+        _ => format!("{}, {time}", locale.date(&kickoff, today.year())),
+    }
+}
+/// The time `until` a kickoff, e.g. `in 45m`, `in 2h 15m` or `in 3d 4h`.
+pub fn countdown(until: Duration, locale: &Locale) -> String {
+    // Round up, so that the countdown never reads `in 0m` before the kickoff.
+    let minutes = until.as_secs().div_ceil(60);
+    match minutes {
+        0 => locale.words().now.to_string(),
+        1..=59 => format!("in {minutes}m"),
+        60..=1439 => format!("in {}h {}m", minutes / 60, minutes % 60),
+        _ => format!("in {}d {}h", minutes / 1440, minutes % 1440 / 60),
+    }
+}
+/// The status of a game as shown in the time column, with the minute while it is played, e.g.
+/// `LIVE 67'`.
+pub fn status_label(game: &Game) -> String {
+    match &game.minute {
+        Some(minute) => format!("{} {minute}'", game.status),
+        None => game.status.to_string(),
+    }
+}
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// Standing Stuff
+/// Prints the league table, followed by a legend of the `zones` when there are colours.
+pub fn print_standings(
+    standings: Vec<Team>,
+    zones: &[Zone],
+    columns: &[Column],
+    settings: &Settings,
+) -> io::Result<()> {
+    let mut out = io::stdout().lock();
+    standings_table(&standings, zones, columns, settings).print(&mut out)?;
+    if colored::control::SHOULD_COLORIZE.should_colorize() && !zones.is_empty() {
+        writeln!(out, "{}", zone_legend(zones, settings))?;
+    }
+    Ok(())
+}
+/// Builds the league table with the position and name of the teams in `zones` coloured and the
+/// rows of the favourite teams in bold. Without `columns`, names are shortened and the wins,
+/// draws, losses and goals left out as far as the layout asks for.
+pub fn standings_table(
+    standings: &[Team],
+    zones: &[Zone],
+    columns: &[Column],
+    settings: &Settings,
+) -> Table {
+    let variants: Vec<(Names, &[Column])> = if columns.is_empty() {
+        vec![
+            (Names::Long, &Column::ALL),
+            (Names::Short, &Column::ALL),
+            (Names::Short, &Column::ESSENTIAL),
+            (Names::Abbrev, &Column::ESSENTIAL),
+        ]
+    } else {
+        vec![
+            (Names::Long, columns),
+            (Names::Short, columns),
+            (Names::Abbrev, columns),
+        ]
+    };
+    settings.layout.pick(&variants, |(names, columns)| {
+        standings_table_with(standings, zones, settings, names, columns)
+    })
+}
+fn standings_table_with(
+    standings: &[Team],
+    zones: &[Zone],
+    settings: &Settings,
+    names: Names,
+    columns: &[Column],
+) -> Table {
+    let mut table = Table::new();
+    table.set_format(*format::consts::FORMAT_BOX_CHARS);
+    let header = settings.locale.words().standings_header;
+    table.add_row(Row::from(
+        columns.iter().map(|&column| header[column as usize]),
+    ));
+    for item in standings {
+        let favourite = settings.favourites.includes(item);
+        let zone = zone_at(zones, item.standing as usize);
+        let name = match names {
+            Names::Long => &item.name,
+            Names::Short if !item.short.is_empty() => &item.short,
+            Names::Abbrev if !item.abbrev.is_empty() => &item.abbrev,
+            _ => &item.name,
+        };
+        table.add_row(Row::from(columns.iter().map(|&column| {
+            let mut cell = match column {
+                Column::Position => item.standing.to_string(),
+                Column::Team => name.clone(),
+                Column::Games => item.games.to_string(),
+                Column::Wins => item.wins.to_string(),
+                Column::Draws => item.draws.to_string(),
+                Column::Losses => item.losses.to_string(),
+                Column::Goals => item.goals(),
+                Column::GoalDiff => goal_difference(item.goal_dif),
+                Column::Points => item.points.to_string(),
+            }
+            .normal();
+            if let Some(zone) = zone.filter(|_| matches!(column, Column::Position | Column::Team)) {
+                cell = cell.color((settings.theme.zones)(zone));
+            }
+            let mut cell = bold_if(cell.to_string(), favourite);
+            if let (Column::Position, Some(change)) = (column, item.change) {
+                cell = format!("{cell} {}", movement(change, settings.theme));
+            }
+            cell
+        })));
+    }
+    table
+}
+/// An arrow with the places a team moved, e.g. `↑2` or `↓1`, blank if it kept its place.
+fn movement(change: i32, theme: &Theme) -> String {
+    match change {
+        0 => "  ".to_string(),
+        1.. => format!("↑{change}").color(theme.rise).to_string(),
+        _ => format!("↓{}", -change).color(theme.fall).to_string(),
+    }
+}
+/// A goal difference with its sign, e.g. `+19`, `0` or `-3`.
+pub fn goal_difference(goal_dif: i32) -> String {
+    if goal_dif > 0 {
+        format!("+{goal_dif}")
+    } else {
+        goal_dif.to_string()
+    }
+}
+/// One line naming the colour of every zone.
+pub fn zone_legend(zones: &[Zone], settings: &Settings) -> String {
+    let mut kinds: Vec<ZoneKind> = zones.iter().map(|zone| zone.kind).collect();
+    kinds.dedup();
+    kinds
+        .into_iter()
+        .map(|kind| {
+            let name = settings.locale.zone_name(kind);
+            format!("{} {name}", "■".color((settings.theme.zones)(kind)))
+        })
+        .collect::<Vec<_>>()
+        .join("  ")
+}
+fn zone_at(zones: &[Zone], position: usize) -> Option<ZoneKind> {
+    zones
+        .iter()
+        .find(|zone| (zone.first..=zone.last).contains(&position))
+        .map(|zone| zone.kind)
+}
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// Fixture Stuff
+/// Prints the name of the club followed by its fixtures as a table.
+pub fn print_fixtures(club: &str, fixtures: &[Fixture], settings: &Settings) -> io::Result<()> {
+    let mut out = io::stdout().lock();
+    writeln!(out, "{club}")?;
+    fixtures_table(fixtures, Utc::now(), settings).print(&mut out)?;
+    Ok(())
+}
+/// Builds the table of the fixtures of one club as of `now`, with a column for the competition
+/// if they span several.
+pub fn fixtures_table(fixtures: &[Fixture], now: DateTime<Utc>, settings: &Settings) -> Table {
+    settings
+        .layout
+        .pick(&[Names::Long, Names::Short, Names::Abbrev], |names| {
+            fixtures_table_with(fixtures, now, settings, names)
+        })
+}
+fn fixtures_table_with(
+    fixtures: &[Fixture],
+    now: DateTime<Utc>,
+    settings: &Settings,
+    names: Names,
+) -> Table {
+    let locale = &settings.locale;
+    let words = locale.words();
+    let theme = settings.theme;
+    let several = fixtures
+        .iter()
+        .any(|fixture| fixture.competition != fixtures[0].competition);
+    let skip = usize::from(!several);
+    let mut table = Table::new();
+    table.set_format(*format::consts::FORMAT_BOX_CHARS);
+    table.add_row(Row::from(&words.fixtures_header[skip..]));
+    for fixture in fixtures {
+        let competition = competition::find(fixture.competition)
+            .map_or(fixture.competition, |competition| competition.name);
+        let matchday = fixture
+            .matchday
+            .map_or_else(String::new, |matchday| matchday.to_string());
+        let date = match fixture.status {
+            MatchStatus::Upcoming => kickoff_label(fixture.kickoff, now, locale),
+            MatchStatus::Over => {
+                let this_year = now.with_timezone(&locale.tz).year();
+                format!(
+                    "{}, {}",
+                    locale.date(&fixture.kickoff, this_year),
+                    locale.time(&fixture.kickoff)
+                )
+            }
+            _ => fixture_status(fixture),
+        };
+        let venue = venue_label(fixture.venue, settings);
+        let mut opponent = team_name(&fixture.opponent, &fixture.opponent_names, names).normal();
+        if settings.favourites.contains(&fixture.opponent) {
+            opponent = opponent.color(theme.favourite);
+        }
+        let mut score = if matches!(
+            fixture.status,
+            MatchStatus::Upcoming | MatchStatus::Postponed
+        ) {
+            "-".normal()
+        } else {
+            format!("{} - {}", fixture.goals_for, fixture.goals_against).normal()
+        };
+        let mut date = date.normal();
+        if fixture.status.is_live() {
+            score = score.color(theme.live);
+            date = date.color(theme.live);
+        }
+        let outcome = fixture
+            .outcome
+            .map_or_else(|| "".normal(), |outcome| outcome_label(outcome, settings));
+        let cells = [
+            competition.normal(),
+            matchday.normal(),
+            date,
+            venue.normal(),
+            opponent,
+            score,
+            outcome,
+        ];
+        table.add_row(Row::from(&cells[skip..]));
+    }
+    table
+}
+/// Prints the dashboard of a club: its neighbourhood in the table with its row in bold, the form
+/// guide, the next and the last game and the lineups of the last game on the pitch.
+pub fn print_dashboard(
+    dashboard: &Dashboard,
+    zones: &[Zone],
+    settings: &Settings,
+) -> io::Result<()> {
+    let now = Utc::now();
+    let words = settings.locale.words();
+    let mut out = io::stdout().lock();
+    writeln!(out, "{}", dashboard.club.bold())?;
+    if !dashboard.table.is_empty() {
+        let settings = Settings {
+            favourites: Favourites::new(std::slice::from_ref(&dashboard.club)),
+            ..*settings
+        };
+        standings_table(&dashboard.table, zones, &[], &settings).print(&mut out)?;
+    }
+    if !dashboard.form.is_empty() {
+        let form: Vec<String> = dashboard
+            .form
+            .iter()
+            .map(|&outcome| outcome_label(outcome, settings).to_string())
+            .collect();
+        writeln!(out, "{}: {}", words.form, form.join(" "))?;
+    }
+    if let Some(next) = &dashboard.next {
+        let when = match next.status {
+            MatchStatus::Upcoming => {
+                let label = kickoff_label(next.kickoff, now, &settings.locale);
+                match (next.kickoff.with_timezone(&Utc) - now).to_std() {
+                    Ok(until) if until >= COUNTDOWN => {
+                        format!("{label} ({})", countdown(until, &settings.locale))
+                    }
+                    _ => label,
+                }
+            }
+            _ => format!(
+                "{} {} - {}",
+                fixture_status(next),
+                next.goals_for,
+                next.goals_against
+            )
+            .color(settings.theme.live)
+            .to_string(),
+        };
+        writeln!(
+            out,
+            "{}: {} {}, {when}",
+            words.next_game,
+            venue_label(next.venue, settings),
+            next.opponent
+        )?;
+    }
+    if let Some(last) = &dashboard.last {
+        let this_year = now.with_timezone(&settings.locale.tz).year();
+        let outcome = last.outcome.map_or_else(String::new, |outcome| {
+            outcome_label(outcome, settings).to_string()
+        });
+        writeln!(
+            out,
+            "{}: {} {} {} - {} {outcome}, {}",
+            words.last_game,
+            venue_label(last.venue, settings),
+            last.opponent,
+            last.goals_for,
+            last.goals_against,
+            settings.locale.date(&last.kickoff, this_year)
+        )?;
+    }
+    if dashboard.lineup.len() == 2 {
+        for line in stadium_lines(&dashboard.lineup, construct_stadium()) {
+            writeln!(out, "{line}")?;
+        }
+    }
+    Ok(())
+}
+fn fixture_status(fixture: &Fixture) -> String {
+    match &fixture.minute {
+        Some(minute) => format!("{} {minute}'", fixture.status),
+        None => fixture.status.to_string(),
+    }
+}
+fn venue_label(venue: Venue, settings: &Settings) -> &'static str {
+    let venues = settings.locale.words().venues;
+    match venue {
+        Venue::Home => venues[0],
+        Venue::Away => venues[1],
+    }
+}
+fn outcome_label(outcome: Outcome, settings: &Settings) -> ColoredString {
+    let (words, theme) = (settings.locale.words(), settings.theme);
+    match outcome {
+        Outcome::Win => words.outcomes[0].color(theme.rise).bold(),
+        Outcome::Draw => words.outcomes[1].normal(),
+        Outcome::Loss => words.outcomes[2].color(theme.fall).bold(),
+    }
+}
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// Line-Up Stuff
+/// Draws an empty pitch, one `String` per character so players can be placed on it.
+pub fn construct_stadium() -> Vec<Vec<String>> {
+    let mut field: Vec<Vec<String>> = Vec::new();
+    let mid_space = " ".to_string().repeat(33);
+    let space_16 = " ".to_string().repeat(10);
+    let space_full = " ".to_string().repeat(44);
+    let border_h = "─".to_string().repeat(44);
+
+    let top: Vec<String> = format!("┌{}┬{}┐", border_h, border_h)
+        .split("")
+        .filter(|&l| !l.is_empty())
+        .map(|l| l.to_string())
+        .collect();
+
+    let mid: Vec<String> = format!("│{}│{}│", space_full, space_full)
+        .split("")
+        .filter(|&l| !l.is_empty())
+        .map(|l| l.to_string())
+        .collect();
+
+    let border16: Vec<String> = format!("├──────────┐{}│{}┌──────────┤", mid_space, mid_space)
+        .split("")
+        .filter(|&l| !l.is_empty())
+        .map(|l| l.to_string())
+        .collect();
+
+    let box16: Vec<String> = format!("│{}│{}│{}│{}│", space_16, mid_space, mid_space, space_16)
+        .split("")
+        .filter(|&l| !l.is_empty())
+        .map(|l| l.to_string())
+        .collect();
+
+    let border16c: Vec<String> = format!("├──────────┘{}│{}└──────────┤", mid_space, mid_space)
+        .split("")
+        .filter(|&l| !l.is_empty())
+        .map(|l| l.to_string())
+        .collect();
+
+    let bottom: Vec<String> = format!("└{}┴{}┘", border_h, border_h)
+        .split("")
+        .filter(|&l| !l.is_empty())
+        .map(|l| l.to_string())
+        .collect();
+
+    field.push(top);
+    for _ in 0..4 {
+        field.push(mid.clone());
+    }
+    field.push(border16);
+    for _ in 0..7 {
+        field.push(box16.clone());
+    }
+    field.push(border16c);
+    for _ in 0..4 {
+        field.push(mid.clone());
+    }
+    field.push(bottom);
+
+    field
+}
+/// Places both lineups on a pitch from [`construct_stadium`] and prints it together with the
+/// player names.
+pub fn populate_stadium(lineups: Vec<LineUp>, stadium: Vec<Vec<String>>) -> io::Result<()> {
+    let mut out = io::stdout().lock();
+    for line in stadium_lines(&lineups, stadium) {
+        writeln!(out, "{line}")?;
+    }
+    Ok(())
+}
+/// Places both lineups on a pitch from [`construct_stadium`] and returns the lines of the pitch
+/// followed by the player names.
+pub fn stadium_lines(lineups: &[LineUp], mut stadium: Vec<Vec<String>>) -> Vec<String> {
+    let width: f32 = 38.0;
+    let height: f32 = 17.0;
+    for player in &lineups[0].players {
+        let x_pos: usize = (width * player.y_pos.clamp(0.0, 1.0)).floor() as usize;
+        let y_pos: usize = (height * player.x_pos.clamp(0.0, 1.0)).floor() as usize;
+
+        if let Some(row_vec) = stadium.get_mut(y_pos + 1) {
+            if x_pos > 1 {
+                if let Some(first_char) = player.number.get(0..1) {
+                    row_vec[x_pos + 6] = first_char.to_string();
+                }
+                if let Some(first_char) = player.number.get(1..2) {
+                    row_vec[x_pos + 7] = first_char.to_string();
+                }
+            } else {
+                if let Some(first_char) = player.number.get(0..1) {
+                    row_vec[x_pos + 1] = first_char.to_string();
+                }
+                if let Some(first_char) = player.number.get(1..2) {
+                    row_vec[x_pos + 2] = first_char.to_string();
+                }
+            }
+        };
+    }
+
+    for player in &lineups[1].players {
+        let x_pos: usize = (width * player.y_pos.clamp(0.0, 1.0)).floor() as usize;
+        let y_pos: usize = (height * player.x_pos.clamp(0.0, 1.0)).floor() as usize;
+        if let Some(row_vec) = stadium.get_mut(y_pos + 1) {
+            let length = row_vec.len();
+            if x_pos > 1 {
+                if let Some(first_char) = player.number.get(0..1) {
+                    row_vec[length - (x_pos + 8)] = first_char.to_string();
+                }
+                if let Some(first_char) = player.number.get(1..2) {
+                    row_vec[length - (x_pos + 7)] = first_char.to_string();
+                }
+            } else {
+                if let Some(first_char) = player.number.get(0..1) {
+                    row_vec[length - (x_pos + 3)] = first_char.to_string();
+                }
+                if let Some(first_char) = player.number.get(1..2) {
+                    row_vec[length - (x_pos + 2)] = first_char.to_string();
+                }
+            }
+        };
+    }
+
+    let home_top_border = top_border(lineups[0].team.chars().count());
+    let away_top_border = top_border(lineups[1].team.chars().count());
+    let space_between: usize = stadium[0]
+        .len()
+        .saturating_sub(home_top_border.chars().count() + away_top_border.chars().count());
+
+    let top: Vec<String> = format!(
+        "{home_top_border}{}{away_top_border}",
+        " ".repeat(space_between)
+    )
+    .split("")
+    .filter(|&l| !l.is_empty()) // Filter out empty strings
+    .map(|l| l.to_string())
+    .collect();
+    let mid: Vec<String> = format!(
+        "│ {} │{}│ {} │",
+        lineups[0].team,
+        " ".repeat(space_between),
+        lineups[1].team
+    )
+    .split("")
+    .filter(|&l| !l.is_empty()) // Filter out empty strings
+    .map(|l| l.to_string())
+    .collect();
+    stadium.insert(0, mid);
+    stadium.insert(0, top);
+    if let Some(row_vec) = stadium.get_mut(2) {
+        let length = row_vec.len() - 1;
+        let home_corner = home_top_border.chars().count() - 1;
+        let away_corner = (length + 1).saturating_sub(away_top_border.chars().count());
+        row_vec[0] = "├".to_string();
+        if home_corner < away_corner {
+            row_vec[home_corner] = "┴".to_string();
+            row_vec[away_corner] = "┴".to_string();
+        }
+        row_vec[length] = "┤".to_string();
+    };
+
+    let mut player_name_table: Vec<String> = Vec::new();
+    const PADDING_WIDTH: usize = 5;
+    let padding = " ".repeat(PADDING_WIDTH);
+    for (player1, player2) in lineups[0].players.iter().zip(lineups[1].players.iter()) {
+        let spaces_right = " ".repeat(stadium[0].len().saturating_sub(
+            player1.name.chars().count() + player2.name.chars().count() + 8 + 2 * PADDING_WIDTH,
+        ));
+        let line: String = format!(
+            "{}{}  {}{}{}  {}{}",
+            padding,
+            player1.number,
+            player1.name,
+            spaces_right,
+            player2.name,
+            player2.number,
+            padding
+        );
+        player_name_table.push(line);
+    }
+    let mut lines: Vec<String> = stadium.into_iter().map(|line| line.join("")).collect();
+    lines.extend(player_name_table);
+    lines
+}
+fn top_border(len: usize) -> String {
+    format!("╭{}╮", "─".repeat(len + 2))
+}
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// Match Event Stuff
+/// Minutes of play per character of the timeline.
+const MINUTES_PER_MARK: u32 = 2;
+/// Prints the score of `game`, the timeline of its `events` and the list of them.
+pub fn print_events(game: &Game, events: &[MatchEvent], settings: &Settings) -> io::Result<()> {
+    let mut out = io::stdout().lock();
+    writeln!(
+        out,
+        "{} {} - {} {}",
+        game.home, game.score_home, game.score_away, game.away
+    )?;
+    for line in timeline_lines(game, events, settings) {
+        writeln!(out, "{line}")?;
+    }
+    if !events.is_empty() {
+        events_table(events, settings).print(&mut out)?;
+    }
+    Ok(())
+}
+/// Draws the timeline of a match: a scale every 15 minutes and one line per team with a mark at
+/// the minute of each of its events. Where events fall together, goals win over cards and cards
+/// over substitutions.
+pub fn timeline_lines(game: &Game, events: &[MatchEvent], settings: &Settings) -> Vec<String> {
+    let last = events.iter().map(|event| event.minute).fold(90, u32::max);
+    let length = (last / MINUTES_PER_MARK + 1) as usize;
+    let names = [
+        team_name(&game.home, &game.home_names, Names::Short),
+        team_name(&game.away, &game.away_names, Names::Short),
+    ];
+    let width = names
+        .iter()
+        .map(|name| name.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    // Room behind the last mark for a label like `120'`.
+    let mut scale = vec![' '; length + 4];
+    for minute in (0..=last).step_by(15) {
+        let at = (minute / MINUTES_PER_MARK) as usize;
+        for (i, c) in format!("{minute}'").chars().enumerate() {
+            scale[at + i] = c;
+        }
+    }
+    let scale: String = scale.into_iter().collect();
+    let mut lines = vec![format!("{:width$} {}", "", scale.trim_end())];
+    for (home, name) in [(true, &names[0]), (false, &names[1])] {
+        let mut marks: Vec<(u8, ColoredString)> = vec![(0, "─".dimmed()); length];
+        for event in events.iter().filter(|event| event.home == home) {
+            let at = (event.minute / MINUTES_PER_MARK) as usize;
+            let mark = event_mark(event.kind, settings.theme);
+            if mark.0 >= marks[at].0 {
+                marks[at] = mark;
+            }
+        }
+        let marks: String = marks.iter().map(|(_, mark)| mark.to_string()).collect();
+        lines.push(format!("{name:width$} {marks}"));
+    }
+    lines
+}
+/// The mark of an event in the timeline, with its weight where several fall together.
+fn event_mark(kind: MatchEventKind, theme: &Theme) -> (u8, ColoredString) {
+    match kind {
+        MatchEventKind::Goal | MatchEventKind::Penalty => (4, "●".bold()),
+        MatchEventKind::OwnGoal => (4, "○".bold()),
+        MatchEventKind::RedCard | MatchEventKind::YellowRedCard => (3, "▮".color(theme.red_card)),
+        MatchEventKind::YellowCard => (2, "▮".color(theme.yellow_card)),
+        MatchEventKind::MissedPenalty => (2, "×".normal()),
+        MatchEventKind::Substitution => (1, "⇄".normal()),
+    }
+}
+/// Lists the events of a match with their minute, team, kind and players.
+pub fn events_table(events: &[MatchEvent], settings: &Settings) -> Table {
+    let words = settings.locale.words();
+    let mut table = Table::new();
+    table.set_format(*format::consts::FORMAT_BOX_CHARS);
+    table.add_row(Row::from(words.events_header));
+    for event in events {
+        let (_, mark) = event_mark(event.kind, settings.theme);
+        let kind = format!("{mark} {}", words.event_kinds[event.kind as usize]);
+        let player = match (&event.assist, event.kind) {
+            (Some(off), MatchEventKind::Substitution) => format!("↑ {} ↓ {off}", event.player),
+            (Some(assist), _) => format!("{} ({assist})", event.player),
+            (None, _) => event.player.clone(),
+        };
+        table.add_row(row![
+            cell!(format!("{}'", event.minute_label())),
+            cell!(event.team),
+            cell!(kind),
+            cell!(player),
+        ]);
+    }
+    table
+}
+/// The goals of one side, one line per scorer with the minutes, e.g. `Kane 9', 58' (P)`. Own
+/// goals are listed with the side they count for.
+fn scorers(events: &[MatchEvent], home: bool) -> Vec<String> {
+    let mut scorers: Vec<(&str, Vec<String>)> = Vec::new();
+    for event in events
+        .iter()
+        .filter(|event| event.home == home && event.kind.is_goal())
+    {
+        let minute = match event.kind {
+            MatchEventKind::Penalty => format!("{}' (P)", event.minute_label()),
+            MatchEventKind::OwnGoal => format!("{}' (OG)", event.minute_label()),
+            _ => format!("{}'", event.minute_label()),
+        };
+        match scorers
+            .iter_mut()
+            .find(|(player, _)| *player == event.player)
+        {
+            Some((_, minutes)) => minutes.push(minute),
+            None => scorers.push((&event.player, vec![minute])),
+        }
+    }
+    scorers
+        .into_iter()
+        .map(|(player, minutes)| format!("{player} {}", minutes.join(", ")))
+        .collect()
+}
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Prints the competitions that can be selected with `--competition`.
+pub fn print_competitions(competitions: &[Competition]) -> io::Result<()> {
+    let mut table = Table::new();
+    table.set_format(*format::consts::FORMAT_BOX_CHARS);
+    table.add_row(row!["Id", "Competition", "Table", "Lineups"]);
+    for competition in competitions {
+        let yes_no = |supported: bool| if supported { "yes" } else { "no" };
+        table.add_row(row![
+            cell!(competition.id),
+            cell!(competition.name),
+            cell!(yes_no(competition.features.standings)),
+            cell!(yes_no(competition.features.lineups)),
+        ]);
+    }
+    table.print(&mut io::stdout().lock())?;
+    Ok(())
+}
+
+/// Prints where the cache lives and what it holds.
+pub fn print_cache_stats(dir: &Path, stats: &CacheStats, locale: &Locale) -> io::Result<()> {
+    let this_year = Utc::now().with_timezone(&locale.tz).year();
+    let format_time = |time: Option<DateTime<Utc>>| {
+        time.map_or_else(
+            || "-".to_string(),
+            |t| format!("{}, {}", locale.date(&t, this_year), locale.time(&t)),
+        )
+    };
+    let mut out = io::stdout().lock();
+    writeln!(out, "Directory: {}", dir.display())?;
+    writeln!(out, "Pages:     {} ({} fresh)", stats.entries, stats.fresh)?;
+    writeln!(out, "Size:      {:.1} KiB", stats.bytes as f64 / 1024.0)?;
+    writeln!(out, "Oldest:    {}", format_time(stats.oldest))?;
+    writeln!(out, "Newest:    {}", format_time(stats.newest))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_label_adds_the_minute_while_playing() {
+        let mut game = Game::test("A", "B", (1, 0), MatchStatus::Live);
+        game.minute = Some("67".to_string());
+        assert_eq!(status_label(&game), "LIVE 67'");
+        game.minute = Some("90+3".to_string());
+        assert_eq!(status_label(&game), "LIVE 90+3'");
+    }
+
+    #[test]
+    fn status_label_of_breaks_and_finished_games() {
+        let half_time = Game::test("A", "B", (1, 0), MatchStatus::HalfTime);
+        assert_eq!(status_label(&half_time), "HT");
+        let over = Game::test("A", "B", (1, 0), MatchStatus::Over);
+        assert_eq!(status_label(&over), "OVER");
+        let postponed = Game::test("A", "B", (0, 0), MatchStatus::Postponed);
+        assert_eq!(status_label(&postponed), "POSTPONED");
+    }
+}
@@ -0,0 +1,284 @@
+//! The games of one club over the season, gathered from every matchday page.
+use chrono::DateTime;
+use chrono_tz::Tz;
+use futures::{future, stream, StreamExt};
+use serde::Serialize;
+use std::cmp::{Ordering, Reverse};
+
+use crate::competition::COMPETITIONS;
+use crate::fuzzy::{self, Query};
+use crate::provider::CONCURRENT_PAGES;
+use crate::{Competition, DataProvider, Game, LineUp, MatchStatus, ShortNames, SoccerError, Team};
+
+/// Whether a club played at home or away.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Venue {
+    Home,
+    Away,
+}
+
+/// How a finished game ended for the club.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Outcome {
+    Win,
+    Draw,
+    Loss,
+}
+
+/// A game seen from one club.
+#[derive(Debug, Serialize)]
+pub struct Fixture {
+    pub club: String,
+    /// Id of the competition, see [`Competition::id`].
+    pub competition: &'static str,
+    pub matchday: Option<u32>,
+    pub venue: Venue,
+    pub opponent: String,
+    /// sportschau's shorter names of the opponent, see [`Game::home_names`].
+    #[serde(skip)]
+    pub opponent_names: ShortNames,
+    pub goals_for: usize,
+    pub goals_against: usize,
+    /// Kickoff, converted to `Europe/Berlin`.
+    pub kickoff: DateTime<Tz>,
+    /// See [`Game::kickoff_known`].
+    pub kickoff_known: bool,
+    pub status: MatchStatus,
+    /// Minute of play while the game is running, see [`Game::minute`].
+    pub minute: Option<String>,
+    /// `None` until the game is over.
+    pub outcome: Option<Outcome>,
+    /// Path of the match page, see [`Game::link`].
+    pub link: String,
+}
+
+impl Fixture {
+    /// `game` from the side of `club`, `None` if the club does not play in it.
+    pub fn new(competition: &'static Competition, game: Game, club: &str) -> Option<Self> {
+        let (venue, opponent, opponent_names, goals_for, goals_against) = if game.home == club {
+            let names = game.away_names;
+            (
+                Venue::Home,
+                game.away,
+                names,
+                game.score_home,
+                game.score_away,
+            )
+        } else if game.away == club {
+            let names = game.home_names;
+            (
+                Venue::Away,
+                game.home,
+                names,
+                game.score_away,
+                game.score_home,
+            )
+        } else {
+            return None;
+        };
+        let outcome =
+            (game.status == MatchStatus::Over).then(|| match goals_for.cmp(&goals_against) {
+                Ordering::Greater => Outcome::Win,
+                Ordering::Equal => Outcome::Draw,
+                Ordering::Less => Outcome::Loss,
+            });
+        Some(Fixture {
+            club: club.to_string(),
+            competition: competition.id,
+            matchday: game.matchday,
+            venue,
+            opponent,
+            opponent_names,
+            goals_for,
+            goals_against,
+            kickoff: game.timestamp,
+            kickoff_known: game.kickoff_known,
+            status: game.status,
+            minute: game.minute,
+            outcome,
+            link: game.link,
+        })
+    }
+}
+
+/// Everything about one club on one screen.
+#[derive(Debug, Serialize)]
+pub struct Dashboard {
+    pub club: String,
+    /// The club's row of the table with the rows above and below it, empty for cups.
+    pub table: Vec<Team>,
+    /// Outcomes of the last five finished games, oldest first.
+    pub form: Vec<Outcome>,
+    /// The game being played or the next one.
+    pub next: Option<Fixture>,
+    /// The last finished game.
+    pub last: Option<Fixture>,
+    /// Starting elevens of the last finished game, empty if sportschau has none.
+    pub lineup: Vec<LineUp>,
+}
+
+/// Games in the form guide of the dashboard.
+pub const FORM_GAMES: usize = 5;
+
+/// Gathers the [`Dashboard`] of the club best matching `query` in `competition`.
+pub async fn dashboard(
+    provider: &dyn DataProvider,
+    competition: &'static Competition,
+    query: &str,
+) -> Result<Dashboard, SoccerError> {
+    let (club, mut fixtures) = fixtures(provider, competition, query, false).await?;
+    let table = match competition.table_url() {
+        Some(table_url) => {
+            let standings = provider.standings(&table_url).await?;
+            match standings.iter().position(|team| team.name == club) {
+                Some(at) => standings[at.saturating_sub(1)..(at + 2).min(standings.len())].to_vec(),
+                None => Vec::new(),
+            }
+        }
+        None => Vec::new(),
+    };
+    let finished = fixtures
+        .iter()
+        .filter(|fixture| fixture.status == MatchStatus::Over)
+        .count();
+    let form = fixtures
+        .iter()
+        .filter_map(|fixture| fixture.outcome)
+        .skip(finished.saturating_sub(FORM_GAMES))
+        .collect();
+    let next = fixtures
+        .iter()
+        .position(|fixture| fixture.status.is_live() || fixture.status == MatchStatus::Upcoming)
+        .map(|at| fixtures.remove(at));
+    let last = fixtures
+        .iter()
+        .rposition(|fixture| fixture.status == MatchStatus::Over)
+        .map(|at| fixtures.swap_remove(at));
+    // Older match pages may be gone, the dashboard does without the pitch then.
+    let lineup = match &last {
+        Some(last) if competition.features.lineups && !last.link.is_empty() => {
+            provider.lineup(&last.link).await.unwrap_or_default()
+        }
+        _ => Vec::new(),
+    };
+    Ok(Dashboard {
+        club,
+        table,
+        form,
+        next,
+        last,
+        lineup,
+    })
+}
+
+/// Every game of the season sportschau lists for `competition`, matchday by matchday, up to the
+/// first matchday sportschau has no page for yet.
+pub async fn season_games(
+    provider: &dyn DataProvider,
+    competition: &Competition,
+) -> Result<Vec<Game>, SoccerError> {
+    let mut pages = stream::iter(1..=competition.matchdays)
+        .map(|matchday| async move {
+            let url = competition.matchday_url(None, matchday);
+            (matchday, provider.scores(&url).await)
+        })
+        .buffered(CONCURRENT_PAGES);
+    let mut games = Vec::new();
+    while let Some((matchday, page)) = pages.next().await {
+        match page {
+            Ok(page) => games.extend(page),
+            // The first matchday has to be there, later ones may not be published yet.
+            Err(e) if e.is_not_found() && matchday > 1 => break,
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(games)
+}
+
+/// The club best matching `query` in the games of `competition` and its fixtures there, and with
+/// `everywhere` also those of every other competition the club is found in, by kickoff.
+pub async fn fixtures(
+    provider: &dyn DataProvider,
+    competition: &'static Competition,
+    query: &str,
+    everywhere: bool,
+) -> Result<(String, Vec<Fixture>), SoccerError> {
+    let games = season_games(provider, competition).await?;
+    let names: Vec<String> = games
+        .iter()
+        .flat_map(|game| [game.home.clone(), game.away.clone()])
+        .collect();
+    let prepared = Query::new(query);
+    let club = names
+        .iter()
+        .filter_map(|name| Some((prepared.score(name)?, name)))
+        // The first of the best, so that ties go to the club of the earliest game.
+        .min_by_key(|&(score, _)| Reverse(score))
+        .map(|(_, name)| name.clone())
+        .ok_or_else(|| SoccerError::NoMatch {
+            query: query.to_string(),
+            closest: fuzzy::closest(query, &names, 3),
+        })?;
+
+    let mut fixtures: Vec<Fixture> = games
+        .into_iter()
+        .filter_map(|game| Fixture::new(competition, game, &club))
+        .collect();
+    if everywhere {
+        let others = COMPETITIONS
+            .iter()
+            .filter(|other| other.id != competition.id);
+        // Each competition fetches its matchdays concurrently already. One that cannot be read
+        // costs only its own games.
+        let club = &club;
+        let found: Vec<Vec<Fixture>> = stream::iter(others)
+            .map(|other| async move {
+                other_fixtures(provider, other, club)
+                    .await
+                    .unwrap_or_else(|e| {
+                        eprintln!("warning: leaving out the {}: {e}", other.name);
+                        Vec::new()
+                    })
+            })
+            .buffered(2)
+            .collect()
+            .await;
+        fixtures.extend(found.into_iter().flatten());
+    }
+    fixtures.sort_by_key(|fixture| fixture.kickoff);
+    Ok((club, fixtures))
+}
+
+/// The fixtures of `club` in `competition`, if it is in the table or plays on the current
+/// matchday. Competitions whose table and current matchday cannot be read count as ones the club
+/// is not in; an error means the club is in it, but its season cannot be read.
+async fn other_fixtures(
+    provider: &dyn DataProvider,
+    competition: &'static Competition,
+    club: &str,
+) -> Result<Vec<Fixture>, SoccerError> {
+    let in_table = async {
+        let table_url = competition.table_url()?;
+        let table = provider.standings(&table_url).await.ok()?;
+        Some(table.iter().any(|team| team.name == club))
+    };
+    let on_matchday = async {
+        let games = provider.scores(&competition.scores_url()).await.ok()?;
+        Some(
+            games
+                .iter()
+                .any(|game| game.home == club || game.away == club),
+        )
+    };
+    let (in_table, on_matchday) = future::join(in_table, on_matchday).await;
+    if in_table != Some(true) && on_matchday != Some(true) {
+        return Ok(Vec::new());
+    }
+    let games = season_games(provider, competition).await?;
+    Ok(games
+        .into_iter()
+        .filter_map(|game| Fixture::new(competition, game, club))
+        .collect())
+}
@@ -0,0 +1,356 @@
+//! League tables beyond the one sportschau prints: home, away and half-season tables computed
+//! from the results, and the columns to sort and print them by.
+use futures::{stream, StreamExt, TryStreamExt};
+use std::cmp::{Ordering, Reverse};
+use std::str::FromStr;
+
+use crate::competition::Tiebreaker;
+use crate::provider::CONCURRENT_PAGES;
+use crate::{fuzzy, Competition, DataProvider, Game, MatchStatus, SoccerError, Team};
+
+/// Which games a league table counts.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TableView {
+    /// Every game, as printed by sportschau.
+    #[default]
+    Overall,
+    /// Only the games at home.
+    Home,
+    /// Only the games away.
+    Away,
+    /// The games of the first round, in which every team plays every other one once.
+    FirstHalf,
+    /// The games of the second round.
+    SecondHalf,
+}
+
+impl FromStr for TableView {
+    type Err = SoccerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "overall" => Ok(TableView::Overall),
+            "home" => Ok(TableView::Home),
+            "away" => Ok(TableView::Away),
+            "first-half" => Ok(TableView::FirstHalf),
+            "second-half" => Ok(TableView::SecondHalf),
+            other => Err(SoccerError::InvalidArgument(format!(
+                "unknown view '{other}', expected one of overall, home, away, first-half, \
+                 second-half"
+            ))),
+        }
+    }
+}
+
+/// A column of the league table. The order is the one of the printed table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Column {
+    Position,
+    Team,
+    Games,
+    Wins,
+    Draws,
+    Losses,
+    Goals,
+    GoalDiff,
+    Points,
+}
+
+impl Column {
+    pub const ALL: [Column; 9] = [
+        Column::Position,
+        Column::Team,
+        Column::Games,
+        Column::Wins,
+        Column::Draws,
+        Column::Losses,
+        Column::Goals,
+        Column::GoalDiff,
+        Column::Points,
+    ];
+    /// The columns left when the table does not fit with all of them.
+    pub const ESSENTIAL: [Column; 5] = [
+        Column::Position,
+        Column::Team,
+        Column::Games,
+        Column::GoalDiff,
+        Column::Points,
+    ];
+}
+
+impl FromStr for Column {
+    type Err = SoccerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "pos" | "position" | "#" => Ok(Column::Position),
+            "team" => Ok(Column::Team),
+            "games" | "gp" => Ok(Column::Games),
+            "wins" | "w" => Ok(Column::Wins),
+            "draws" | "d" => Ok(Column::Draws),
+            "losses" | "l" => Ok(Column::Losses),
+            "goals" => Ok(Column::Goals),
+            "diff" | "gd" => Ok(Column::GoalDiff),
+            "points" | "p" => Ok(Column::Points),
+            other => Err(SoccerError::InvalidArgument(format!(
+                "unknown column '{other}', expected one of pos, team, games, wins, draws, \
+                 losses, goals, diff, points"
+            ))),
+        }
+    }
+}
+
+/// Sorts `teams` by `column`: positions and names ascending, everything else descending, so
+/// that e.g. `Wins` puts the team with the most wins first. Ties keep their order.
+pub fn sort(teams: &mut [Team], column: Column) {
+    match column {
+        Column::Position => teams.sort_by_key(|team| team.standing),
+        Column::Team => teams.sort_by_key(|team| fuzzy::normalize(&team.name)),
+        Column::Games => teams.sort_by_key(|team| Reverse(team.games)),
+        Column::Wins => teams.sort_by_key(|team| Reverse(team.wins)),
+        Column::Draws => teams.sort_by_key(|team| Reverse(team.draws)),
+        Column::Losses => teams.sort_by_key(|team| Reverse(team.losses)),
+        Column::Goals => teams.sort_by_key(|team| Reverse(team.goals_for)),
+        Column::GoalDiff => teams.sort_by_key(|team| Reverse(team.goal_dif)),
+        Column::Points => teams.sort_by_key(|team| Reverse(team.points)),
+    }
+}
+
+/// Fetches the table at `table_url` and, for any other view than the overall one, recomputes it
+/// from the results of every matchday played so far.
+pub async fn fetch(
+    provider: &dyn DataProvider,
+    competition: &Competition,
+    table_url: &str,
+    view: TableView,
+) -> Result<Vec<Team>, SoccerError> {
+    let overall = provider.standings(table_url).await?;
+    if view == TableView::Overall {
+        return Ok(overall);
+    }
+    let played = overall.iter().map(|team| team.games).max().unwrap_or(0);
+    // A round has one matchday less than there are teams.
+    let round = overall.len().saturating_sub(1) as u32;
+    let matchdays = match view {
+        TableView::FirstHalf => 1..=played.min(round),
+        TableView::SecondHalf => round + 1..=played,
+        _ => 1..=played,
+    };
+    let pages: Vec<Vec<Game>> = stream::iter(matchdays)
+        .map(|matchday| async move {
+            provider
+                .scores(&competition.matchday_url(None, matchday))
+                .await
+        })
+        .buffered(CONCURRENT_PAGES)
+        .try_collect()
+        .await?;
+    let games: Vec<Game> = pages.into_iter().flatten().collect();
+    Ok(tabulate(&overall, &games, view, competition.tiebreakers))
+}
+
+/// Builds the table of `view` from the finished `games`. Ids and names come from the `overall`
+/// table, so teams without any counted game are listed as well.
+pub fn tabulate(
+    overall: &[Team],
+    games: &[Game],
+    view: TableView,
+    tiebreakers: &[Tiebreaker],
+) -> Vec<Team> {
+    let mut teams: Vec<Team> = overall
+        .iter()
+        .map(|team| Team {
+            id: team.id,
+            standing: 0,
+            name: team.name.clone(),
+            short: team.short.clone(),
+            abbrev: team.abbrev.clone(),
+            games: 0,
+            wins: 0,
+            draws: 0,
+            losses: 0,
+            goals_for: 0,
+            goals_against: 0,
+            goal_dif: 0,
+            points: 0,
+            change: None,
+        })
+        .collect();
+    for game in games.iter().filter(|game| game.status == MatchStatus::Over) {
+        let sides = [
+            (
+                &game.home,
+                game.score_home,
+                game.score_away,
+                view != TableView::Away,
+            ),
+            (
+                &game.away,
+                game.score_away,
+                game.score_home,
+                view != TableView::Home,
+            ),
+        ];
+        for (name, scored, conceded, counted) in sides {
+            if !counted {
+                continue;
+            }
+            if let Some(team) = teams.iter_mut().find(|team| team.name == *name) {
+                record(team, scored as u32, conceded as u32);
+            }
+        }
+    }
+    rank(&mut teams, tiebreakers);
+    teams
+}
+
+/// The table as it stands: the `official` one with the current scores of the live `games`
+/// counted as results, ranked again and with the places every team moved. Without a live game
+/// it is the official table as it is.
+pub fn project(official: &[Team], games: &[Game], tiebreakers: &[Tiebreaker]) -> Vec<Team> {
+    let mut teams = official.to_vec();
+    if !games.iter().any(|game| game.status.is_live()) {
+        return teams;
+    }
+    for game in games.iter().filter(|game| game.status.is_live()) {
+        let sides = [
+            (&game.home, game.score_home, game.score_away),
+            (&game.away, game.score_away, game.score_home),
+        ];
+        for (name, scored, conceded) in sides {
+            if let Some(team) = teams.iter_mut().find(|team| team.name == *name) {
+                record(team, scored as u32, conceded as u32);
+            }
+        }
+    }
+    rank(&mut teams, tiebreakers);
+    for team in &mut teams {
+        let before = official.iter().find(|row| row.id == team.id);
+        team.change = before.map(|row| row.standing as i32 - team.standing as i32);
+    }
+    teams
+}
+
+/// Orders `teams` by points and then by `tiebreakers`, and numbers their places. Head-to-head
+/// comparisons would need every result, so they are skipped; teams still level keep their order.
+pub fn rank(teams: &mut [Team], tiebreakers: &[Tiebreaker]) {
+    teams.sort_by(|a, b| {
+        tiebreakers
+            .iter()
+            .filter(|&&tiebreaker| tiebreaker != Tiebreaker::HeadToHead)
+            .fold(b.points.cmp(&a.points), |order, tiebreaker| {
+                order.then_with(|| match tiebreaker {
+                    Tiebreaker::GoalDifference => b.goal_dif.cmp(&a.goal_dif),
+                    Tiebreaker::GoalsScored => b.goals_for.cmp(&a.goals_for),
+                    Tiebreaker::HeadToHead => Ordering::Equal,
+                })
+            })
+    });
+    for (position, team) in teams.iter_mut().enumerate() {
+        team.standing = position as u32 + 1;
+    }
+}
+
+fn record(team: &mut Team, scored: u32, conceded: u32) {
+    team.games += 1;
+    team.goals_for += scored;
+    team.goals_against += conceded;
+    team.goal_dif += scored as i32 - conceded as i32;
+    match scored.cmp(&conceded) {
+        Ordering::Greater => {
+            team.wins += 1;
+            team.points += 3;
+        }
+        Ordering::Equal => {
+            team.draws += 1;
+            team.points += 1;
+        }
+        Ordering::Less => team.losses += 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::competition::Tiebreaker::*;
+
+    const GOALS: &[Tiebreaker] = &[GoalDifference, GoalsScored];
+
+    fn team(id: u32, name: &str, points: u32, goals_for: u32, goals_against: u32) -> Team {
+        Team {
+            id,
+            standing: id,
+            name: name.to_string(),
+            short: name.to_string(),
+            abbrev: name.to_string(),
+            games: 0,
+            wins: 0,
+            draws: 0,
+            losses: 0,
+            goals_for,
+            goals_against,
+            goal_dif: goals_for as i32 - goals_against as i32,
+            points,
+            change: None,
+        }
+    }
+
+    fn names(teams: &[Team]) -> Vec<&str> {
+        teams.iter().map(|team| team.name.as_str()).collect()
+    }
+
+    #[test]
+    fn tabulate_counts_finished_games_of_the_view() {
+        let overall = [team(1, "A", 0, 0, 0), team(2, "B", 0, 0, 0)];
+        let games = [
+            Game::test("A", "B", (2, 0), MatchStatus::Over),
+            Game::test("B", "A", (1, 0), MatchStatus::Over),
+            Game::test("A", "B", (0, 5), MatchStatus::Live),
+        ];
+        let table = tabulate(&overall, &games, TableView::Overall, GOALS);
+        assert_eq!(names(&table), ["A", "B"]);
+        assert_eq!(
+            (table[0].games, table[0].points, table[0].goal_dif),
+            (2, 3, 1)
+        );
+
+        let home = tabulate(&overall, &games, TableView::Home, GOALS);
+        assert_eq!(names(&home), ["A", "B"]);
+        assert_eq!((home[0].points, home[1].points), (3, 3));
+        assert_eq!((home[0].goals_for, home[0].goals_against), (2, 0));
+    }
+
+    #[test]
+    fn rank_applies_goals_when_head_to_head_comes_first() {
+        let mut teams = [team(1, "A", 10, 5, 5), team(2, "B", 10, 9, 3)];
+        rank(&mut teams, &[HeadToHead, GoalDifference, GoalsScored]);
+        assert_eq!(names(&teams), ["B", "A"]);
+        assert_eq!((teams[0].standing, teams[1].standing), (1, 2));
+    }
+
+    #[test]
+    fn project_counts_live_scores_and_the_places_moved() {
+        let official = [
+            team(1, "A", 10, 8, 4),
+            team(2, "B", 9, 6, 4),
+            team(3, "C", 8, 5, 5),
+        ];
+        let games = [
+            Game::test("C", "A", (2, 0), MatchStatus::Live),
+            Game::test("B", "D", (1, 1), MatchStatus::Over),
+        ];
+        let table = project(&official, &games, GOALS);
+        assert_eq!(names(&table), ["C", "A", "B"]);
+        let changes: Vec<Option<i32>> = table.iter().map(|team| team.change).collect();
+        assert_eq!(changes, [Some(2), Some(-1), Some(-1)]);
+    }
+
+    #[test]
+    fn project_without_live_games_is_the_official_table() {
+        let official = [team(1, "A", 10, 5, 5), team(2, "B", 10, 9, 3)];
+        let games = [Game::test("A", "B", (0, 3), MatchStatus::Over)];
+        let table = project(&official, &games, GOALS);
+        assert_eq!(names(&table), ["A", "B"]);
+        assert!(table.iter().all(|team| team.change.is_none()));
+    }
+}
@@ -0,0 +1,93 @@
+//! Colours of the tables.
+use colored::Color;
+
+use crate::competition::ZoneKind;
+use crate::SoccerError;
+
+/// The colours picked for one kind of terminal background.
+#[derive(Debug)]
+pub struct Theme {
+    pub name: &'static str,
+    /// Score and minute of games being played.
+    pub live: Color,
+    /// Scores that changed since the last update in `soccer watch`.
+    pub changed: Color,
+    /// Names of the favourite teams.
+    pub favourite: Color,
+    /// Arrows of teams climbing or dropping in the live table.
+    pub rise: Color,
+    pub fall: Color,
+    /// Cards in the timeline of a match.
+    pub yellow_card: Color,
+    pub red_card: Color,
+    /// Position and name of the teams in each zone of the table.
+    pub zones: fn(ZoneKind) -> Color,
+}
+
+/// Every theme. The first one is the default.
+pub const THEMES: &[Theme] = &[
+    Theme {
+        name: "dark",
+        live: Color::BrightGreen,
+        changed: Color::BrightYellow,
+        favourite: Color::BrightCyan,
+        rise: Color::BrightGreen,
+        fall: Color::BrightRed,
+        yellow_card: Color::BrightYellow,
+        red_card: Color::BrightRed,
+        zones: |zone| match zone {
+            ZoneKind::ChampionsLeague | ZoneKind::RoundOf16 => Color::BrightBlue,
+            ZoneKind::EuropaLeague | ZoneKind::KnockoutPlayoff => Color::Yellow,
+            ZoneKind::ConferenceLeague => Color::Cyan,
+            ZoneKind::Promotion => Color::BrightGreen,
+            ZoneKind::PromotionPlayoff => Color::Green,
+            ZoneKind::RelegationPlayoff => Color::Magenta,
+            ZoneKind::Relegation => Color::BrightRed,
+        },
+    },
+    Theme {
+        name: "light",
+        live: Color::Green,
+        changed: Color::Magenta,
+        favourite: Color::Blue,
+        rise: Color::Green,
+        fall: Color::Red,
+        yellow_card: Color::TrueColor {
+            r: 0xb3,
+            g: 0x6b,
+            b: 0x00,
+        },
+        red_card: Color::Red,
+        zones: |zone| match zone {
+            ZoneKind::ChampionsLeague | ZoneKind::RoundOf16 => Color::Blue,
+            ZoneKind::EuropaLeague | ZoneKind::KnockoutPlayoff => Color::TrueColor {
+                r: 0xb3,
+                g: 0x6b,
+                b: 0x00,
+            },
+            ZoneKind::ConferenceLeague => Color::Cyan,
+            ZoneKind::Promotion => Color::Green,
+            ZoneKind::PromotionPlayoff => Color::TrueColor {
+                r: 0x2e,
+                g: 0x7d,
+                b: 0x32,
+            },
+            ZoneKind::RelegationPlayoff => Color::Magenta,
+            ZoneKind::Relegation => Color::Red,
+        },
+    },
+];
+
+/// Looks up a theme by its name.
+pub fn find(name: &str) -> Result<&'static Theme, SoccerError> {
+    THEMES
+        .iter()
+        .find(|theme| theme.name.eq_ignore_ascii_case(name))
+        .ok_or_else(|| {
+            let names: Vec<&str> = THEMES.iter().map(|theme| theme.name).collect();
+            SoccerError::InvalidArgument(format!(
+                "unknown theme '{name}', expected one of {}",
+                names.join(", ")
+            ))
+        })
+}
@@ -0,0 +1,215 @@
+//! Keeps a view on screen and refreshes it while games are being played.
+use chrono::{DateTime, Utc};
+use colored::Colorize;
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::time::Duration;
+
+use crate::render::Settings;
+use crate::{render, Competition, DataProvider, Game, MatchStatus, SoccerError};
+
+/// Polling interval while at least one game is live.
+pub const LIVE_INTERVAL: Duration = Duration::from_secs(30);
+/// Longest pause between two polls, so that postponed kickoffs and corrections show up.
+pub const IDLE_INTERVAL: Duration = Duration::from_secs(30 * 60);
+/// Polling interval after a failed update.
+const RETRY_INTERVAL: Duration = Duration::from_secs(60);
+
+/// What to keep on screen.
+pub enum View {
+    Scores,
+    Standings,
+    /// The lineups of the game with this link (see [`Game::link`]), headed by its score.
+    Match(String),
+}
+
+/// How long to wait before polling again: [`LIVE_INTERVAL`] while a game is live, otherwise
+/// until the next kickoff, but never longer than [`IDLE_INTERVAL`].
+pub fn next_poll(games: &[Game], now: DateTime<Utc>) -> Duration {
+    if games.iter().any(|game| game.status.is_live()) {
+        return LIVE_INTERVAL;
+    }
+    games
+        .iter()
+        .filter(|game| game.status == MatchStatus::Upcoming)
+        .filter_map(|game| (game.timestamp.with_timezone(&Utc) - now).to_std().ok())
+        .min()
+        .map_or(IDLE_INTERVAL, |until_kickoff| {
+            until_kickoff.clamp(LIVE_INTERVAL, IDLE_INTERVAL)
+        })
+}
+
+/// Redraws `view` in place until the process is interrupted.
+pub async fn watch(
+    provider: &dyn DataProvider,
+    competition: &Competition,
+    view: View,
+    settings: &Settings,
+) -> Result<(), SoccerError> {
+    let scores_url = competition.scores_url();
+    let table_url = competition.table_url();
+    if matches!(view, View::Standings) && table_url.is_none() {
+        return Err(SoccerError::InvalidArgument(format!(
+            "the {} has no table",
+            competition.name
+        )));
+    }
+    // Scores of the previous poll, to highlight the games that changed since.
+    let mut previous: HashMap<(String, String), (usize, usize)> = HashMap::new();
+    loop {
+        let result = poll(
+            provider,
+            competition,
+            &scores_url,
+            table_url.as_deref(),
+            &view,
+            settings,
+            &previous,
+        )
+        .await;
+        let (frame, interval) = match result {
+            Ok((frame, games)) => {
+                let interval = next_poll(&games, Utc::now());
+                previous = games
+                    .into_iter()
+                    .map(|g| ((g.home, g.away), (g.score_home, g.score_away)))
+                    .collect();
+                (frame, interval)
+            }
+            Err(e) => (
+                format!("{}\n", format!("Update failed: {e}").red()),
+                RETRY_INTERVAL,
+            ),
+        };
+        let status = format!(
+            "Updated {} · next update in {} · Ctrl-C to quit",
+            Utc::now()
+                .with_timezone(&settings.locale.tz)
+                .format("%H:%M:%S"),
+            format_interval(interval)
+        );
+        redraw(&format!("{frame}{}\n", status.dimmed()))?;
+        tokio::time::sleep(interval).await;
+    }
+}
+
+/// Fetches everything `view` needs and renders it. Returns the rendered frame together with
+/// the games of the matchday, which decide when to poll next.
+async fn poll(
+    provider: &dyn DataProvider,
+    competition: &Competition,
+    scores_url: &str,
+    table_url: Option<&str>,
+    view: &View,
+    settings: &Settings,
+    previous: &HashMap<(String, String), (usize, usize)>,
+) -> Result<(String, Vec<Game>), SoccerError> {
+    let changed = |game: &Game| {
+        previous
+            .get(&(game.home.clone(), game.away.clone()))
+            .is_some_and(|&score| score != (game.score_home, game.score_away))
+    };
+    let games = provider.scores(scores_url).await?;
+    let frame = match view {
+        View::Scores => render::scores_table(&games, Utc::now(), settings, changed).to_string(),
+        View::Standings => {
+            let standings = provider.standings(table_url.unwrap_or_default()).await?;
+            render::standings_table(&standings, competition.zones, &[], settings).to_string()
+        }
+        View::Match(link) => {
+            let lineups = provider.lineup(link).await?;
+            let mut frame = String::new();
+            if let Some(game) = games.iter().find(|game| &game.link == link) {
+                let mut score = format!("{} - {}", game.score_home, game.score_away);
+                if changed(game) {
+                    score = score.bold().color(settings.theme.changed).to_string();
+                }
+                frame.push_str(&format!(
+                    "{} {score} {}  {}\n",
+                    game.home,
+                    game.away,
+                    render::status_label(game)
+                ));
+            }
+            for line in render::stadium_lines(&lineups, render::construct_stadium()) {
+                frame.push_str(&line);
+                frame.push('\n');
+            }
+            frame
+        }
+    };
+    Ok((frame, games))
+}
+
+/// Replaces the screen content with `frame` without clearing it first, which would flicker.
+/// Without colours, e.g. with `--color never` or into a pipe, the frames are printed one after
+/// the other instead, as the cursor movements are escape sequences as well.
+fn redraw(frame: &str) -> io::Result<()> {
+    let mut out = io::stdout().lock();
+    if !colored::control::SHOULD_COLORIZE.should_colorize() {
+        writeln!(out, "{frame}")?;
+        return out.flush();
+    }
+    write!(out, "\x1b[H")?;
+    for line in frame.lines() {
+        // Overwrite the line, then erase what is left of the previous frame on it.
+        writeln!(out, "{line}\x1b[K")?;
+    }
+    write!(out, "\x1b[J")?;
+    out.flush()
+}
+
+fn format_interval(interval: Duration) -> String {
+    let secs = interval.as_secs();
+    match secs {
+        0..=59 => format!("{secs}s"),
+        60..=3599 => format!("{}m", secs / 60),
+        _ => format!("{}h {}m", secs / 3600, secs % 3600 / 60),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeDelta;
+
+    /// A game kicking off `until_kickoff` after the returned time.
+    fn upcoming(until_kickoff: TimeDelta) -> (Game, DateTime<Utc>) {
+        let game = Game::test("A", "B", (0, 0), MatchStatus::Upcoming);
+        let now = game.timestamp.with_timezone(&Utc) - until_kickoff;
+        (game, now)
+    }
+
+    #[test]
+    fn live_games_are_polled_often() {
+        let (game, now) = upcoming(TimeDelta::hours(2));
+        let live = Game::test("C", "D", (1, 0), MatchStatus::Live);
+        assert_eq!(next_poll(&[game, live], now), LIVE_INTERVAL);
+    }
+
+    #[test]
+    fn imminent_kickoffs_are_clamped_to_the_live_interval() {
+        let (game, now) = upcoming(TimeDelta::seconds(5));
+        assert_eq!(next_poll(&[game], now), LIVE_INTERVAL);
+    }
+
+    #[test]
+    fn polls_wait_for_the_next_kickoff() {
+        let (game, now) = upcoming(TimeDelta::minutes(10));
+        assert_eq!(next_poll(&[game], now), Duration::from_secs(10 * 60));
+    }
+
+    #[test]
+    fn distant_kickoffs_are_clamped_to_the_idle_interval() {
+        let (game, now) = upcoming(TimeDelta::days(3));
+        assert_eq!(next_poll(&[game], now), IDLE_INTERVAL);
+    }
+
+    #[test]
+    fn without_upcoming_games_polls_are_idle() {
+        let over = Game::test("A", "B", (2, 1), MatchStatus::Over);
+        let now = over.timestamp.with_timezone(&Utc) + TimeDelta::hours(3);
+        assert_eq!(next_poll(&[over], now), IDLE_INTERVAL);
+        assert_eq!(next_poll(&[], now), IDLE_INTERVAL);
+    }
+}
@@ -0,0 +1,109 @@
+use chrono::{DateTime, TimeZone, Utc};
+use chrono_tz::America::New_York;
+use chrono_tz::Europe::{Berlin, London};
+use chrono_tz::Tz;
+use soccer::locale::{Clock, Language};
+use soccer::render::kickoff_label;
+use soccer::Locale;
+
+fn berlin(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Tz> {
+    Berlin.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+}
+
+fn utc(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+}
+
+fn label(kickoff: DateTime<Tz>, now: DateTime<Utc>) -> String {
+    kickoff_label(kickoff, now, &Locale::default())
+}
+
+#[test]
+fn minutes_are_zero_padded() {
+    let now = utc(2024, 10, 19, 8, 0);
+    assert_eq!(label(berlin(2024, 10, 19, 18, 5), now), "Today, 18:05");
+}
+
+#[test]
+fn tomorrow_across_month_and_year() {
+    let now = utc(2024, 10, 31, 12, 0);
+    assert_eq!(label(berlin(2024, 11, 1, 15, 30), now), "Tomorrow, 15:30");
+    let now = utc(2024, 12, 31, 12, 0);
+    assert_eq!(label(berlin(2025, 1, 1, 15, 30), now), "Tomorrow, 15:30");
+}
+
+#[test]
+fn weekday_within_a_week_then_date() {
+    let now = utc(2024, 10, 28, 12, 0);
+    assert_eq!(label(berlin(2024, 11, 2, 15, 30), now), "Sat, 15:30");
+    assert_eq!(label(berlin(2024, 11, 9, 15, 30), now), "Sat 9 Nov, 15:30");
+    assert_eq!(
+        label(berlin(2025, 1, 11, 15, 30), now),
+        "Sat 11 Jan 2025, 15:30"
+    );
+}
+
+#[test]
+fn countdown_shortly_before_kickoff() {
+    let now = utc(2024, 10, 19, 11, 15);
+    assert_eq!(label(berlin(2024, 10, 19, 15, 30), now), "in 2h 15m");
+    assert_eq!(label(berlin(2024, 10, 19, 13, 45), now), "in 30m");
+    assert_eq!(label(berlin(2024, 10, 19, 13, 15), now), "now");
+}
+
+#[test]
+fn days_are_counted_in_the_display_time_zone() {
+    // 23:30 UTC is already the next day in Berlin.
+    let now = utc(2024, 10, 18, 23, 30);
+    assert_eq!(label(berlin(2024, 10, 19, 15, 30), now), "Today, 15:30");
+}
+
+#[test]
+fn delayed_kickoff_shows_the_scheduled_time() {
+    let now = utc(2024, 10, 19, 14, 0);
+    assert_eq!(label(berlin(2024, 10, 19, 15, 30), now), "Today, 15:30");
+}
+
+#[test]
+fn other_time_zones_and_clocks() {
+    let now = utc(2024, 10, 19, 8, 0);
+    let london = Locale {
+        tz: London,
+        ..Locale::default()
+    };
+    assert_eq!(
+        kickoff_label(berlin(2024, 10, 19, 18, 30), now, &london),
+        "Today, 17:30"
+    );
+    let new_york = Locale {
+        tz: New_York,
+        clock: Clock::H12,
+        ..Locale::default()
+    };
+    // 00:30 in Berlin is still the evening before in New York.
+    assert_eq!(
+        kickoff_label(berlin(2024, 10, 20, 0, 30), now, &new_york),
+        "Today, 6:30 PM"
+    );
+}
+
+#[test]
+fn german_words() {
+    let german = Locale {
+        language: Language::German,
+        ..Locale::default()
+    };
+    let now = utc(2024, 10, 28, 12, 0);
+    assert_eq!(
+        kickoff_label(berlin(2024, 10, 29, 20, 45), now, &german),
+        "Morgen, 20:45"
+    );
+    assert_eq!(
+        kickoff_label(berlin(2024, 11, 2, 15, 30), now, &german),
+        "Sa, 15:30"
+    );
+    assert_eq!(
+        kickoff_label(berlin(2024, 11, 9, 15, 30), now, &german),
+        "Sa 09.11., 15:30"
+    );
+}
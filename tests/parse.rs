@@ -0,0 +1,189 @@
+use soccer::competition;
+use soccer::provider::{
+    parse_events, parse_lineup, parse_match_status, parse_scores, parse_standings,
+};
+use soccer::{DataProvider, Fixtures, MatchEventKind, MatchStatus};
+use std::fs;
+use std::path::PathBuf;
+
+const BUNDESLIGA: &str = "fixtures/live-und-ergebnisse/fussball/deutschland-bundesliga";
+
+fn page(path: &str) -> String {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join(BUNDESLIGA)
+        .join(path);
+    fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {e}", path.display()))
+}
+
+#[test]
+fn scores_of_the_current_matchday() {
+    let games = parse_scores(&page("spiele-und-ergebnisse.html")).unwrap();
+    assert_eq!(games.len(), 9);
+
+    let first = &games[0];
+    assert_eq!(
+        (first.home.as_str(), first.away.as_str()),
+        ("Borussia Dortmund", "FC St. Pauli")
+    );
+    assert_eq!((first.score_home, first.score_away), (2, 1));
+    assert_eq!(
+        (
+            first.home_names.abbrev.as_str(),
+            first.away_names.short.as_str()
+        ),
+        ("BVB", "St. Pauli")
+    );
+    assert_eq!(first.status, MatchStatus::Over);
+    assert_eq!(first.matchday, Some(7));
+    assert!(first.kickoff_known);
+    assert_eq!(first.timestamp.to_rfc3339(), "2024-10-18T20:30:00+02:00");
+    assert_eq!(
+        first.link,
+        "/live-und-ergebnisse/fussball/deutschland-bundesliga/se55001/borussia-dortmund_fc-st-pauli/spielinfos"
+    );
+
+    let live = games
+        .iter()
+        .find(|game| game.status == MatchStatus::Live)
+        .unwrap();
+    assert_eq!(live.home, "Eintracht Frankfurt");
+    assert_eq!(live.minute.as_deref(), Some("67"));
+    assert!(games
+        .iter()
+        .filter(|game| game.status == MatchStatus::Upcoming)
+        .all(|game| (game.score_home, game.score_away) == (0, 0) && game.minute.is_none()));
+}
+
+#[test]
+fn standings_are_numbers() {
+    let teams = parse_standings(&page("tabelle.html")).unwrap();
+    assert_eq!(teams.len(), 18);
+    let positions: Vec<u32> = teams.iter().map(|team| team.standing).collect();
+    assert_eq!(positions, (1..=18).collect::<Vec<u32>>());
+
+    let bayern = &teams[0];
+    assert_eq!(bayern.id, 14);
+    assert_eq!(
+        (
+            bayern.name.as_str(),
+            bayern.short.as_str(),
+            bayern.abbrev.as_str()
+        ),
+        ("FC Bayern München", "Bayern", "FCB")
+    );
+    assert_eq!(
+        (bayern.games, bayern.wins, bayern.draws, bayern.losses),
+        (7, 5, 2, 0)
+    );
+    assert_eq!(
+        (bayern.goals_for, bayern.goals_against, bayern.goal_dif),
+        (25, 6, 19)
+    );
+    assert_eq!(bayern.points, 17);
+    assert!(teams.iter().all(|team| team.inconsistencies().is_empty()));
+    assert!(teams.iter().any(|team| team.goal_dif < 0));
+}
+
+#[test]
+fn malformed_rows_are_skipped() {
+    let body = page("tabelle.html").replace(
+        r#"<td class="goals">25:6</td>"#,
+        r#"<td class="goals">25-6</td>"#,
+    );
+    let teams = parse_standings(&body).unwrap();
+    assert_eq!(teams.len(), 17);
+    assert!(teams.iter().all(|team| team.name != "FC Bayern München"));
+}
+
+#[test]
+fn rows_that_do_not_add_up_are_inconsistent() {
+    // Bayern with a point too many, a game too few and a goal difference off by one.
+    let body = page("tabelle.html")
+        .replace(
+            r#"<td class="games">7</td><td class="wins">5</td><td class="draws">2</td><td class="losses">0</td><td class="goals">25:6</td><td class="goal-difference">+19</td><td class="points">17</td>"#,
+            r#"<td class="games">6</td><td class="wins">5</td><td class="draws">2</td><td class="losses">0</td><td class="goals">25:6</td><td class="goal-difference">+18</td><td class="points">18</td>"#,
+        );
+    let teams = parse_standings(&body).unwrap();
+    let found = teams[0].inconsistencies();
+    assert_eq!(found.len(), 3, "{found:?}");
+    assert!(found[0].contains("18 points"));
+    assert!(found[1].contains("played 6 games"));
+    assert!(found[2].contains("goal difference of 18"));
+    assert!(teams[1..]
+        .iter()
+        .all(|team| team.inconsistencies().is_empty()));
+}
+
+#[test]
+fn lineups_of_both_sides() {
+    let body = page("se55002/fc-bayern-muenchen_vfb-stuttgart/taktische-aufstellung.html");
+    let lineups = parse_lineup(&body).unwrap();
+    assert_eq!(lineups.len(), 2);
+    assert_eq!(
+        (lineups[0].team.as_str(), lineups[1].team.as_str()),
+        ("Bayern", "Stuttgart")
+    );
+    assert!(lineups.iter().all(|lineup| lineup.players.len() == 11));
+
+    let keeper = &lineups[0].players[0];
+    assert_eq!(
+        (keeper.number.as_str(), keeper.name.as_str()),
+        ("01", "Neuer")
+    );
+    // The away side is mirrored onto the other half of the pitch.
+    let away_keeper = &lineups[1].players[0];
+    assert_eq!(away_keeper.name, "Nübel");
+    assert!((away_keeper.x_pos - 0.5).abs() < f32::EPSILON);
+    assert_eq!(parse_match_status(&body).unwrap(), Some(MatchStatus::Over));
+}
+
+#[test]
+fn events_in_the_order_they_happened() {
+    let body = page("se55001/borussia-dortmund_fc-st-pauli/spielinfos.html");
+    let events = parse_events(&body).unwrap();
+    // The whistles are not events.
+    assert_eq!(events.len(), 8);
+    let minutes: Vec<String> = events.iter().map(|event| event.minute_label()).collect();
+    assert_eq!(minutes, ["14", "31", "40", "52", "60", "68", "75", "90+4"]);
+
+    let first = &events[0];
+    assert_eq!(first.kind, MatchEventKind::Goal);
+    assert_eq!((first.team.as_str(), first.home), ("St. Pauli", false));
+    assert_eq!(
+        (first.player.as_str(), first.assist.as_deref()),
+        ("Saad", Some("Irvine"))
+    );
+    assert_eq!(events[5].kind, MatchEventKind::YellowRedCard);
+    assert_eq!((events[7].minute, events[7].added), (90, Some(4)));
+    assert_eq!(parse_match_status(&body).unwrap(), Some(MatchStatus::Over));
+}
+
+#[test]
+fn events_of_the_same_minute_keep_their_order() {
+    let body = r#"<ul class="hs-ticker">
+        <li class="hs-event yellow-card away" data-minute="52"><span class="player">Wahl</span></li>
+        <li class="hs-event goal home" data-minute="52"><span class="player">Guirassy</span></li>
+    </ul>"#;
+    let events = parse_events(body).unwrap();
+    assert_eq!(events[0].kind, MatchEventKind::Goal);
+    assert_eq!(events[1].kind, MatchEventKind::YellowCard);
+    assert_eq!(parse_match_status(body).unwrap(), None);
+}
+
+#[test]
+fn pages_without_a_table_or_lineup_are_errors() {
+    assert!(parse_standings("<html><body></body></html>").is_err());
+    assert!(parse_lineup("<html><body></body></html>").is_err());
+    assert!(parse_events("<html><body></body></html>").is_err());
+}
+
+#[tokio::test]
+async fn fixtures_provider_maps_urls_to_files() {
+    let provider = Fixtures::new(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("fixtures"));
+    let bundesliga = competition::find("bundesliga").unwrap();
+    let games = provider.scores(&bundesliga.scores_url()).await.unwrap();
+    let lineups = provider.lineup(&games[1].link).await.unwrap();
+    assert_eq!(lineups[0].team, "Bayern");
+    let missing = provider.scores(&bundesliga.matchday_url(None, 34)).await;
+    assert!(missing.unwrap_err().is_not_found());
+}